@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use powercela::{GameOfLife, Point};
+
+// ~50k live cells: a 316x316 random soup at roughly half density.
+fn soup() -> HashSet<Point> {
+    const SIDE: i64 = 316;
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    let mut cells = HashSet::new();
+    for y in 0..SIDE {
+        for x in 0..SIDE {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state.is_multiple_of(2) {
+                cells.insert(Point::new(x, y));
+            }
+        }
+    }
+    cells
+}
+
+fn neighbor_count(c: &mut Criterion) {
+    const GENERATIONS: usize = 5;
+    let cells = soup();
+    let game = || GameOfLife::builder().live_cells(cells.clone()).build().unwrap();
+
+    let mut serial = game();
+    let mut simd = game();
+    serial.simulate(GENERATIONS, None);
+    simd.simulate_simd(GENERATIONS);
+    assert_eq!(serial.live_cells, simd.live_cells);
+
+    let mut group = c.benchmark_group("neighbor_count");
+    group.sample_size(10);
+    group.bench_function("serial", |b| b.iter_batched(game, |mut g| g.simulate(GENERATIONS, None), BatchSize::LargeInput));
+    group.bench_function("simd", |b| b.iter_batched(game, |mut g| g.simulate_simd(GENERATIONS), BatchSize::LargeInput));
+    group.finish();
+}
+
+criterion_group!(benches, neighbor_count);
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use powercela::{split_pattern, GameOfLife, RandomSpec};
+
+fn quiet_game() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game
+}
+
+fn random_game(side: u32, seed: u64) -> GameOfLife {
+    let mut game = quiet_game();
+    game.load_random(&RandomSpec { width: side, height: side, density: 0.5, seed }).unwrap();
+    game
+}
+
+// Every benchmark steps clones of the same starting game with the serial and
+// the parallel stepper.
+fn serial_and_parallel(c: &mut Criterion, name: &str, game: &GameOfLife, generations: usize) {
+    let mut serial = game.clone();
+    let mut parallel = game.clone();
+    serial.simulate(generations, None);
+    parallel.simulate_parallel(generations);
+    assert_eq!(serial.live_cells, parallel.live_cells);
+
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+    group.bench_function("serial", |b| {
+        b.iter_batched(|| game.clone(), |mut g| g.simulate(generations, None), BatchSize::LargeInput)
+    });
+    group.bench_function("parallel", |b| {
+        b.iter_batched(|| game.clone(), |mut g| g.simulate_parallel(generations), BatchSize::LargeInput)
+    });
+    group.finish();
+}
+
+fn bench_simulate_glider_gun_10k(c: &mut Criterion) {
+    let mut game = quiet_game();
+    game.load_named("gosper-glider-gun", 0, 0).unwrap();
+    serial_and_parallel(c, "bench_simulate_glider_gun_10k", &game, 10_000);
+}
+
+fn bench_simulate_random_dense_1k(c: &mut Criterion) {
+    serial_and_parallel(c, "bench_simulate_random_dense_1k", &random_game(100, 1), 1_000);
+}
+
+// One generation of a ~100k-cell soup.
+fn bench_neighbor_count(c: &mut Criterion) {
+    let game = random_game(448, 2);
+    assert!(game.live_cells.len() > 95_000);
+    serial_and_parallel(c, "bench_neighbor_count", &game, 1);
+}
+
+fn bench_split_pattern(c: &mut Criterion) {
+    let pattern: String = (0..10_000).map(|i| if i % 3 == 0 { '1' } else { '0' }).collect();
+    let mut group = c.benchmark_group("bench_split_pattern");
+    for width in [8, 100, 1_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(width), &width, |b, &width| {
+            b.iter(|| split_pattern(&pattern, width).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_simulate_glider_gun_10k,
+    bench_simulate_random_dense_1k,
+    bench_neighbor_count,
+    bench_split_pattern
+);
+criterion_main!(benches);
@@ -0,0 +1,12 @@
+// Generates the gRPC messages, server and client from proto/powercela.proto
+// for the grpc feature. `protox` parses the file, so `protoc` is not needed.
+fn main() {
+    println!("cargo:rerun-if-changed=proto/powercela.proto");
+    #[cfg(feature = "grpc")]
+    {
+        let descriptors = protox::compile(["proto/powercela.proto"], ["proto"]).expect("proto/powercela.proto is invalid");
+        tonic_prost_build::configure()
+            .compile_fds(descriptors)
+            .expect("generating the gRPC code failed");
+    }
+}
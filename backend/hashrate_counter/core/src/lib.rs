@@ -0,0 +1,160 @@
+// The types of powercela that need nothing from `std`, the life-like rules,
+// stability detection and a stepping function over them, for targets without
+// an allocator-backed `std` such as embedded boards. `powercela` re-exports
+// all of these.
+#![no_std]
+
+extern crate alloc;
+
+mod rule;
+mod stability;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+pub use rule::{Rule, RuleParseError};
+pub use stability::{current_stability_score, detect_phase, StabilityTracker};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Topology {
+    #[default]
+    Infinite,
+    Torus { width: u32, height: u32 },
+}
+
+impl Topology {
+    pub fn is_infinite(&self) -> bool {
+        *self == Topology::Infinite
+    }
+
+    pub fn wrap(&self, point: Point) -> Point {
+        match *self {
+            Topology::Infinite => point,
+            Topology::Torus { width, height } => Point::new(
+                point.x.checked_rem_euclid(width as i64).unwrap_or(point.x),
+                point.y.checked_rem_euclid(height as i64).unwrap_or(point.y),
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TopologyParseError(pub alloc::string::String);
+
+impl fmt::Display for TopologyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid torus dimensions {:?}, expected <width>x<height>", self.0)
+    }
+}
+
+impl core::error::Error for TopologyParseError {}
+
+// "infinite", or "<width>x<height>" for a torus.
+impl FromStr for Topology {
+    type Err = TopologyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "infinite" {
+            return Ok(Topology::Infinite);
+        }
+        let invalid = || TopologyParseError(s.into());
+        let (width, height) = s.split_once('x').ok_or_else(invalid)?;
+        let width: u32 = width.parse().map_err(|_| invalid())?;
+        let height: u32 = height.parse().map_err(|_| invalid())?;
+        Ok(Topology::Torus { width, height })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StabilizationKind {
+    FixedPoint(usize),
+    // The first generation of a period of at most 32 that repeated across the
+    // last 64 generations.
+    Oscillator { generation: usize, period: usize },
+    // The first generation of a longer period, up to 64, found by
+    // `detect_phase` across the last 128 generations.
+    Periodic { period: usize, generation: usize },
+    // The first generation with no live cells.
+    Extinction(usize),
+    // The first generation above `max_population`.
+    Explosion { generation: usize, population: usize },
+    // The first generation above `max_cells`.
+    MemoryLimitExceeded { generation: usize, count: usize },
+    // The first generation at which the variance of the last `window`
+    // populations fell below `threshold`.
+    LowVariance { generation: usize, threshold: f64, window: usize },
+    None,
+}
+
+impl StabilizationKind {
+    pub fn generation(&self) -> Option<usize> {
+        match *self {
+            StabilizationKind::FixedPoint(generation) => Some(generation),
+            StabilizationKind::Oscillator { generation, .. } => Some(generation),
+            StabilizationKind::Periodic { generation, .. } => Some(generation),
+            StabilizationKind::Extinction(generation) => Some(generation),
+            StabilizationKind::Explosion { generation, .. } => Some(generation),
+            StabilizationKind::MemoryLimitExceeded { generation, .. } => Some(generation),
+            StabilizationKind::LowVariance { generation, .. } => Some(generation),
+            StabilizationKind::None => None,
+        }
+    }
+
+    // Whether the run ended because the pattern settled down, rather than by
+    // hitting a limit or running out of iterations.
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self,
+            StabilizationKind::FixedPoint(_)
+                | StabilizationKind::Oscillator { .. }
+                | StabilizationKind::Periodic { .. }
+                | StabilizationKind::Extinction(_)
+                | StabilizationKind::LowVariance { .. }
+        )
+    }
+}
+
+// One generation of `cells`. `offsets` are the neighborhood, e.g.
+// `Neighborhood::offsets` in `powercela`, and `next_state` is the rule, called
+// with whether a cell is alive and its number of live neighbors.
+pub fn step<F>(cells: &BTreeSet<Point>, topology: Topology, offsets: &[(i64, i64)], next_state: F) -> BTreeSet<Point>
+where
+    F: Fn(bool, u8) -> bool,
+{
+    let mut neighbor_counts: BTreeMap<Point, u8> = BTreeMap::new();
+    for &cell in cells {
+        for &(dx, dy) in offsets {
+            let neighbor = topology.wrap(Point::new(cell.x.wrapping_add(dx), cell.y.wrapping_add(dy)));
+            *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+        }
+    }
+
+    // Live cells with no live neighbors never show up in the counts.
+    let mut next: BTreeSet<Point> = cells
+        .iter()
+        .filter(|cell| !neighbor_counts.contains_key(cell) && next_state(true, 0))
+        .copied()
+        .collect();
+    for (cell, count) in neighbor_counts {
+        if next_state(cells.contains(&cell), count) {
+            next.insert(cell);
+        }
+    }
+    next
+}
@@ -0,0 +1,179 @@
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub birth: BTreeSet<u8>,
+    pub survival: BTreeSet<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleParseError {
+    InvalidFormat(String),
+    InvalidNeighborCount(char),
+    InvalidCountList(String),
+    UnsupportedB0,
+}
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleParseError::InvalidFormat(rule) => write!(f, "invalid rule {:?}, expected B/S notation like B3/S23", rule),
+            RuleParseError::InvalidNeighborCount(count) => write!(f, "invalid neighbor count {:?}", count),
+            RuleParseError::InvalidCountList(item) => {
+                write!(f, "invalid neighbor count {:?}, expected a count or a range like 10-12", item)
+            }
+            RuleParseError::UnsupportedB0 => write!(f, "rules with B0 are not supported"),
+        }
+    }
+}
+
+impl core::error::Error for RuleParseError {}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+impl Rule {
+    pub fn conway() -> Self {
+        Rule {
+            birth: BTreeSet::from([3]),
+            survival: BTreeSet::from([2, 3]),
+        }
+    }
+
+    // B3678/S34678, which treats live and dead cells symmetrically: inverting
+    // every cell of a pattern inverts its whole evolution.
+    pub fn day_and_night() -> Self {
+        Rule {
+            birth: BTreeSet::from([3, 6, 7, 8]),
+            survival: BTreeSet::from([3, 4, 6, 7, 8]),
+        }
+    }
+
+    // B36/S23, Conway's rule plus birth on six neighbors. Known for its
+    // replicator, which copies itself along a diagonal.
+    pub fn highlife() -> Self {
+        Rule {
+            birth: BTreeSet::from([3, 6]),
+            survival: BTreeSet::from([2, 3]),
+        }
+    }
+
+    // Rules with a common name, as accepted by `--rule`.
+    pub fn named(name: &str) -> Option<Rule> {
+        match name.to_ascii_lowercase().as_str() {
+            "day-and-night" => Some(Rule::day_and_night()),
+            "highlife" => Some(Rule::highlife()),
+            _ => None,
+        }
+    }
+
+    // Accepts Golly B/S notation ("B36/S23", "b2/s", "S23/B3") as well as the
+    // older survival/birth digit form ("23/3"). Counts above 9, for larger
+    // neighborhoods, use comma-separated counts and ranges ("B10-12,15/S8-14").
+    pub fn parse_bs(s: &str) -> Result<Rule, RuleParseError> {
+        let invalid = || RuleParseError::InvalidFormat(s.to_string());
+        let (first, second) = s.trim().split_once('/').ok_or_else(invalid)?;
+
+        let mut birth = None;
+        let mut survival = None;
+        for part in [first, second] {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some('B') | Some('b') if birth.is_none() => birth = Some(parse_counts(chars.as_str())?),
+                Some('S') | Some('s') if survival.is_none() => survival = Some(parse_counts(chars.as_str())?),
+                _ => {}
+            }
+        }
+
+        let (birth, survival) = match (birth, survival) {
+            (Some(birth), Some(survival)) => (birth, survival),
+            (None, None) => (parse_counts(second)?, parse_counts(first)?),
+            _ => return Err(invalid()),
+        };
+
+        if birth.contains(&0) {
+            return Err(RuleParseError::UnsupportedB0);
+        }
+        Ok(Rule { birth, survival })
+    }
+
+    // The largest birth or survival count, to check against a neighborhood.
+    pub fn max_count(&self) -> Option<u8> {
+        self.birth.iter().chain(&self.survival).copied().max()
+    }
+
+    pub fn next_state(&self, alive: bool, neighbors: u8) -> bool {
+        if alive {
+            self.survival.contains(&neighbors)
+        } else {
+            self.birth.contains(&neighbors)
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = RuleParseError;
+
+    // A name from `Rule::named` or B/S notation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Rule::named(s.trim()) {
+            Some(rule) => Ok(rule),
+            None => Rule::parse_bs(s),
+        }
+    }
+}
+
+fn parse_counts(digits: &str) -> Result<BTreeSet<u8>, RuleParseError> {
+    if digits.contains([',', '-']) {
+        return parse_count_list(digits);
+    }
+    digits
+        .chars()
+        .map(|ch| match ch.to_digit(10) {
+            Some(count) => Ok(count as u8),
+            None => Err(RuleParseError::InvalidNeighborCount(ch)),
+        })
+        .collect()
+}
+
+fn parse_count_list(list: &str) -> Result<BTreeSet<u8>, RuleParseError> {
+    let mut counts = BTreeSet::new();
+    for item in list.split(',') {
+        let invalid = || RuleParseError::InvalidCountList(item.to_string());
+        let (low, high) = item.split_once('-').unwrap_or((item, item));
+        let low: u8 = low.parse().map_err(|_| invalid())?;
+        let high: u8 = high.parse().map_err(|_| invalid())?;
+        if low > high {
+            return Err(invalid());
+        }
+        counts.extend(low..=high);
+    }
+    Ok(counts)
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "B")?;
+        write_counts(f, &self.birth)?;
+        write!(f, "/S")?;
+        write_counts(f, &self.survival)
+    }
+}
+
+// Single digits while every count fits in one, comma-separated otherwise.
+fn write_counts(f: &mut fmt::Formatter<'_>, counts: &BTreeSet<u8>) -> fmt::Result {
+    let separator = if counts.last().is_some_and(|&count| count > 9) { "," } else { "" };
+    for (i, count) in counts.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", separator)?;
+        }
+        write!(f, "{}", count)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+
+use crate::StabilizationKind;
+
+// Oscillations up to `OSCILLATOR_PERIOD` are reported once they have repeated
+// across twice as many generations; longer ones, up to `MAX_PERIOD`, only after
+// twice that.
+const OSCILLATOR_PERIOD: usize = 32;
+const MAX_PERIOD: usize = 64;
+
+// Watches one population per generation for a fixed point lasting the
+// stability window, for an oscillation of period 2 to 32 across the last 64
+// generations or of a longer period up to 64 across the last 128, and
+// optionally for a low variance. A tracker started at a later generation
+// begins a fresh window.
+pub struct StabilityTracker {
+    window: usize,
+    history: VecDeque<usize>,
+    unchanged_for: usize,
+    variance: Option<VarianceStop>,
+}
+
+// The last `window` populations with their running sums.
+struct VarianceStop {
+    threshold: f64,
+    window: usize,
+    recent: VecDeque<usize>,
+    sum: u128,
+    sum_of_squares: u128,
+}
+
+impl VarianceStop {
+    // The variance once `population` is added, when the window is full.
+    fn push(&mut self, population: usize) -> Option<f64> {
+        if self.recent.len() == self.window {
+            let oldest = self.recent.pop_front().unwrap_or_default() as u128;
+            self.sum -= oldest;
+            self.sum_of_squares -= oldest * oldest;
+        }
+        self.recent.push_back(population);
+        self.sum += population as u128;
+        self.sum_of_squares += population as u128 * population as u128;
+        if self.recent.len() < self.window {
+            return None;
+        }
+        let n = self.window as f64;
+        let mean = self.sum as f64 / n;
+        Some((self.sum_of_squares as f64 / n - mean * mean).max(0.0))
+    }
+}
+
+impl StabilityTracker {
+    pub fn new(window: usize, population: usize) -> Self {
+        let mut history = VecDeque::with_capacity(MAX_PERIOD * 2);
+        history.push_back(population);
+        StabilityTracker { window, history, unchanged_for: 1, variance: None }
+    }
+
+    // Also stop once the variance of the last `window` populations, the
+    // starting one included, is below `threshold`.
+    pub fn with_variance_stop(mut self, stop: Option<(f64, usize)>, population: usize) -> Self {
+        self.variance = stop.map(|(threshold, window)| {
+            let mut stop = VarianceStop { threshold, window: window.max(1), recent: VecDeque::new(), sum: 0, sum_of_squares: 0 };
+            stop.push(population);
+            stop
+        });
+        self
+    }
+
+    pub fn observe(&mut self, generation: usize, population: usize) -> Option<StabilizationKind> {
+        let previous = self.history.back().copied();
+        self.unchanged_for = if previous == Some(population) { self.unchanged_for + 1 } else { 1 };
+        if generation >= self.window && self.unchanged_for >= self.window {
+            return Some(StabilizationKind::FixedPoint(generation - self.window + 1));
+        }
+
+        if self.history.len() == MAX_PERIOD * 2 {
+            self.history.pop_front();
+        }
+        self.history.push_back(population);
+        let history = self.history.make_contiguous();
+        if let Some(period) = detect_phase(history, OSCILLATOR_PERIOD) {
+            let generation = generation + 1 - OSCILLATOR_PERIOD * 2;
+            return Some(StabilizationKind::Oscillator { generation, period });
+        }
+        if let Some(period) = detect_phase(history, MAX_PERIOD) {
+            let generation = generation + 1 - MAX_PERIOD * 2;
+            return Some(StabilizationKind::Periodic { period, generation });
+        }
+
+        let stop = self.variance.as_mut()?;
+        match stop.push(population) {
+            Some(variance) if variance < stop.threshold => {
+                Some(StabilizationKind::LowVariance { generation, threshold: stop.threshold, window: stop.window })
+            }
+            _ => None,
+        }
+    }
+
+    // `current_stability_score` once `population` is observed.
+    pub fn score_with(&self, population: usize) -> f64 {
+        let unchanged_for = if self.history.back() == Some(&population) { self.unchanged_for + 1 } else { 1 };
+        unchanged_for.min(self.window) as f64 / self.window.max(1) as f64
+    }
+
+    // The log line for a stop `observe` reported.
+    pub fn describe(&self, kind: StabilizationKind, population: usize) -> String {
+        match kind {
+            StabilizationKind::FixedPoint(generation) => format!(
+                "Simulation stopped at generation {}: Population stabilized at {} for {} generations",
+                generation, population, self.window
+            ),
+            StabilizationKind::Oscillator { generation, period } | StabilizationKind::Periodic { period, generation } => {
+                format!("Simulation stopped at generation {}: Population oscillating with period {}", generation, period)
+            }
+            StabilizationKind::LowVariance { generation, threshold, window } => format!(
+                "Simulation stopped at generation {}: Population variance below {} over the last {} generations",
+                generation, threshold, window
+            ),
+            other => format!("Simulation stopped: {:?}", other),
+        }
+    }
+}
+
+// How much of the stability window the last populations in `history` have
+// been constant for, from 0.0 (no history) to 1.0 (at least `window`
+// generations). A population that just changed counts as one generation.
+pub fn current_stability_score(history: &[usize], window: usize) -> f64 {
+    let Some(&last) = history.last() else {
+        return 0.0;
+    };
+    let unchanged_for = history.iter().rev().take_while(|&&population| population == last).count();
+    unchanged_for.min(window) as f64 / window.max(1) as f64
+}
+
+// Smallest period p in 2..=max_period such that the last 2 * max_period
+// populations repeat every p generations, or `None` while `history` is
+// shorter than that. A constant history is a fixed point, which is left to
+// the stability window.
+pub fn detect_phase(history: &[usize], max_period: usize) -> Option<usize> {
+    if max_period < 2 || history.len() < max_period * 2 {
+        return None;
+    }
+    let history = &history[history.len() - max_period * 2..];
+    if history.iter().all(|&pop| pop == history[0]) {
+        return None;
+    }
+    (2..=max_period).find(|&period| (0..history.len() - period).all(|i| history[i] == history[i + period]))
+}
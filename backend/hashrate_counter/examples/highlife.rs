@@ -0,0 +1,27 @@
+use std::time::Instant;
+use powercela::{GameOfLife, Rule, StabilizationKind};
+
+// The HighLife replicator copies itself along a diagonal, and the copies keep
+// replicating, so the population grows without bound until the cell limit
+// stops the run. Growth is slow and uneven (a few hundred cells after 1000
+// generations), far too slow for the naive stepper, so hashlife runs up to 2^33
+// generations and the limit stops it at the first power of two past it. Run
+// with `cargo run --release --example highlife`.
+fn main() {
+    const MAX_CELLS: usize = 500_000;
+    const REPLICATOR: [&str; 5] = ["00111", "01001", "10001", "10010", "11100"];
+
+    let mut game = GameOfLife::builder().rule(Rule::highlife()).max_cells(MAX_CELLS).build().unwrap();
+    game.initialize_pattern_centered(&REPLICATOR).unwrap();
+    game.set_quiet(true);
+
+    let start = Instant::now();
+    let kind = game.simulate_hashlife(1 << 33);
+    println!("{:?} after {:?}", kind, start.elapsed());
+    match kind {
+        StabilizationKind::MemoryLimitExceeded { generation, count } => {
+            println!("{} live cells at generation {} passed the limit of {}", count, generation, MAX_CELLS)
+        }
+        other => panic!("expected the replicator to exceed {} cells, got {:?}", MAX_CELLS, other),
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::Instant;
+use powercela::GameOfLife;
+
+// Informal serial vs. parallel comparison on a ~100k-cell random soup.
+// Run with `cargo run --release --example parallel_bench`.
+fn main() {
+    const SIDE: usize = 450;
+    const GENERATIONS: usize = 20;
+
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    let rows: Vec<String> = (0..SIDE)
+        .map(|_| {
+            (0..SIDE)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    if state.is_multiple_of(2) { '1' } else { '0' }
+                })
+                .collect()
+        })
+        .collect();
+    let pattern: Vec<&str> = rows.iter().map(|s| s.as_str()).collect();
+
+    let mut serial = GameOfLife::new();
+    serial.initialize_pattern(&pattern, 0, 0).unwrap();
+    let mut parallel = GameOfLife::new();
+    parallel.initialize_pattern(&pattern, 0, 0).unwrap();
+    println!("Live cells: {}", serial.live_cells.len());
+
+    let start = Instant::now();
+    let serial_kind = serial.simulate(GENERATIONS, None);
+    let serial_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel_kind = parallel.simulate_parallel(GENERATIONS);
+    let parallel_time = start.elapsed();
+
+    assert_eq!(serial_kind, parallel_kind);
+    assert_eq!(serial.generation_data, parallel.generation_data);
+    assert_eq!(serial.live_cells, parallel.live_cells);
+
+    println!("Serial:   {:?}", serial_time);
+    println!("Parallel: {:?}", parallel_time);
+}
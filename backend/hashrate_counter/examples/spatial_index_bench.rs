@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+use std::time::Instant;
+use powercela::{GameOfLife, Point, SpatialIndex};
+
+// Naive vs. quadtree-indexed neighbor counting on ~1M live cells spread as
+// random clusters across a huge coordinate range.
+// Run with `cargo run --release --example spatial_index_bench`.
+fn main() {
+    const TARGET_CELLS: usize = 1_000_000;
+    const CLUSTER_SIDE: i64 = 64;
+    const SPREAD: i64 = 1 << 24;
+    const GENERATIONS: usize = 5;
+
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut cells = HashSet::new();
+    while cells.len() < TARGET_CELLS {
+        let origin_x = (next() % SPREAD as u64) as i64 - SPREAD / 2;
+        let origin_y = (next() % SPREAD as u64) as i64 - SPREAD / 2;
+        for y in 0..CLUSTER_SIDE {
+            for x in 0..CLUSTER_SIDE {
+                if next().is_multiple_of(2) {
+                    cells.insert(Point::new(origin_x + x, origin_y + y));
+                }
+            }
+        }
+    }
+    println!("Live cells: {}", cells.len());
+    println!("Quadtree leaves: {}", SpatialIndex::build(&cells).leaf_count());
+
+    let mut naive = GameOfLife::builder().live_cells(cells.clone()).build().unwrap();
+    let mut indexed = GameOfLife::builder().live_cells(cells).spatial_index(true).build().unwrap();
+
+    let start = Instant::now();
+    let naive_kind = naive.simulate(GENERATIONS, None);
+    let naive_time = start.elapsed();
+
+    let start = Instant::now();
+    let indexed_kind = indexed.simulate(GENERATIONS, None);
+    let indexed_time = start.elapsed();
+
+    assert_eq!(naive_kind, indexed_kind);
+    assert_eq!(naive.generation_data, indexed.generation_data);
+    assert_eq!(naive.live_cells, indexed.live_cells);
+
+    println!("Naive:   {:?}", naive_time);
+    println!("Indexed: {:?}", indexed_time);
+}
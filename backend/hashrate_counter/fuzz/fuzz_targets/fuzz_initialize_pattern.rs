@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use powercela::GameOfLife;
+
+fuzz_target!(|input: (Vec<&str>, i64, i64)| {
+    let (rows, start_x, start_y) = input;
+    let mut game = GameOfLife::new();
+    let valid = rows.iter().all(|row| row.chars().all(|ch| ch == '0' || ch == '1'));
+    assert_eq!(game.initialize_pattern(&rows, start_x, start_y).is_ok(), valid);
+    if valid {
+        let live: usize = rows.iter().map(|row| row.matches('1').count()).sum();
+        assert_eq!(game.live_cells.len(), live);
+    }
+});
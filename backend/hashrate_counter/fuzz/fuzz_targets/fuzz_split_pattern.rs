@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use powercela::split_pattern;
+
+fuzz_target!(|input: (&str, usize)| {
+    let (pattern, split_amount) = input;
+    match split_pattern(pattern, split_amount) {
+        Ok(rows) => {
+            assert_eq!(rows.concat(), pattern);
+            assert!(rows.iter().all(|row| row.chars().count() <= split_amount));
+        }
+        Err(_) => assert_eq!(split_amount, 0),
+    }
+});
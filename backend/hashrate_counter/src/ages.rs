@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use crate::GameOfLife;
+
+impl GameOfLife {
+    pub fn track_ages(&self) -> bool {
+        self.track_ages
+    }
+
+    // Opt-in because `cell_ages` holds an entry for every live cell.
+    pub fn set_track_ages(&mut self, enabled: bool) {
+        self.track_ages = enabled;
+        if !enabled {
+            self.cell_ages.clear();
+        }
+    }
+
+    // Cells added since the last step count as newly born.
+    pub(crate) fn sync_ages(&mut self) {
+        if !self.track_ages {
+            return;
+        }
+        let live_cells = &self.live_cells;
+        self.cell_ages.retain(|cell, _| live_cells.contains(cell));
+        for &cell in live_cells {
+            self.cell_ages.entry(cell).or_insert(1);
+        }
+    }
+
+    // Survivors age by one, births start at 1, and dead cells drop out.
+    pub(crate) fn advance_ages(&mut self) {
+        if !self.track_ages {
+            return;
+        }
+        let mut ages = HashMap::with_capacity(self.live_cells.len());
+        for &cell in &self.live_cells {
+            ages.insert(cell, self.cell_ages.get(&cell).map_or(1, |age| age + 1));
+        }
+        self.cell_ages = ages;
+    }
+}
@@ -0,0 +1,63 @@
+use std::io;
+
+use tokio::task;
+
+use crate::{GameOfLife, PowercelaError, SimulationResult};
+
+impl GameOfLife {
+    // Advances one generation on Tokio's blocking thread pool, so callers on
+    // the runtime are not held up by the neighbor counting, and returns the
+    // new population. Like `steps`, there are no stability checks. Only the
+    // live cells and the rule are sent to the pool; the history stays here and
+    // gains the new generation once it is back. Panics when called outside a
+    // Tokio runtime.
+    pub async fn step_async(&mut self) -> Result<usize, PowercelaError> {
+        if self.live_cells.is_empty() {
+            return Ok(0);
+        }
+        let stepper = GameOfLife {
+            live_cells: self.live_cells.clone(),
+            neighborhood: self.neighborhood.clone(),
+            neighbor_offsets: self.neighbor_offsets.clone(),
+            topology: self.topology,
+            rule: self.rule.clone(),
+            spatial_index: self.spatial_index,
+            ..GameOfLife::new()
+        };
+        let live_cells = on_blocking_pool(move || stepper.next_generation()).await?;
+        self.sync_ages();
+        let generation = self.generation + 1;
+        self.advance_to(generation, live_cells);
+        self.generation_data.insert(generation, self.live_cells.len());
+        Ok(self.live_cells.len())
+    }
+
+    // `simulate(iterations, None)` on the blocking thread pool. The game keeps
+    // its final cells and `generation_data`, as after `simulate`. The run works
+    // on one copy of the game, history included, that replaces it once done.
+    pub async fn simulate_async(&mut self, iterations: usize) -> Result<SimulationResult, PowercelaError> {
+        let mut copy = self.clone();
+        let (copy, result) = on_blocking_pool(move || {
+            let fingerprint = copy.pattern_fingerprint();
+            let stabilization_kind = copy.simulate(iterations, None);
+            let result = SimulationResult::from_game(copy.clone(), stabilization_kind, fingerprint);
+            (copy, result)
+        })
+        .await?;
+        *self = copy;
+        Ok(result)
+    }
+}
+
+// `spawn_blocking` needs a `'static` closure, so callers hand `f` copies and
+// only write back to the game once it is done. A future dropped before then,
+// e.g. by `tokio::select!` or a timeout, leaves the game as it was. Fails with
+// `Interrupted` when the runtime shuts down before the task starts; a panic in
+// `f` is resumed here.
+async fn on_blocking_pool<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> Result<T, PowercelaError> {
+    match task::spawn_blocking(f).await {
+        Ok(output) => Ok(output),
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(_) => Err(io::Error::new(io::ErrorKind::Interrupted, "the blocking task was cancelled").into()),
+    }
+}
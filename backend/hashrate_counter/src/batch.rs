@@ -0,0 +1,23 @@
+use rayon::prelude::*;
+use tracing::info;
+
+use crate::{GameOfLife, SimulationResult};
+
+// Runs every pattern on its own rayon task with the serial stepper and returns
+// the results in input order. Progress output is turned off; each pattern
+// prints one line when it finishes instead.
+pub fn batch_simulate(patterns: Vec<(String, GameOfLife)>, iterations: usize) -> Vec<(String, SimulationResult)> {
+    patterns
+        .into_par_iter()
+        .map(|(name, mut game)| {
+            game.set_quiet(true);
+            let fingerprint = game.pattern_fingerprint();
+            let kind = game.simulate(iterations, None);
+            match kind.generation() {
+                Some(generation) => info!("{}: stopped at generation {} ({:?})", name, generation, kind),
+                None => info!("{}: ran all {} generations", name, iterations),
+            }
+            (name, SimulationResult::from_game(game, kind, fingerprint))
+        })
+        .collect()
+}
@@ -0,0 +1,53 @@
+use crate::GameOfLife;
+
+impl GameOfLife {
+    // Records in `birth_generation` the generation each live cell appeared in.
+    pub fn with_birth_tracking(mut self) -> Self {
+        self.set_birth_tracking(true);
+        self
+    }
+
+    pub fn birth_tracking(&self) -> bool {
+        self.birth_tracking
+    }
+
+    pub fn set_birth_tracking(&mut self, enabled: bool) {
+        self.birth_tracking = enabled;
+        if !enabled {
+            self.birth_generation.clear();
+        }
+    }
+
+    // Cells new since the last call were born in `generation`; cells that died drop out.
+    pub(crate) fn record_births(&mut self, generation: usize) {
+        if !self.birth_tracking {
+            return;
+        }
+        let live_cells = &self.live_cells;
+        self.birth_generation.retain(|cell, _| live_cells.contains(cell));
+        for &cell in live_cells {
+            self.birth_generation.entry(cell).or_insert(generation);
+        }
+    }
+
+    // Generations since the longest-lived cell was born.
+    pub fn oldest_cell_age(&self) -> Option<usize> {
+        let born = self.birth_generation.values().min()?;
+        Some(self.generation.saturating_sub(*born))
+    }
+
+    // Live cells per age bucket, where an age is generations since birth.
+    // Bucket 0 holds age 0 and bucket k holds ages 2^(k-1) to 2^k - 1.
+    pub fn age_histogram(&self) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        for &born in self.birth_generation.values() {
+            let age = self.generation.saturating_sub(born);
+            let bucket = (usize::BITS - age.leading_zeros()) as usize;
+            if histogram.len() <= bucket {
+                histogram.resize(bucket + 1, 0);
+            }
+            histogram[bucket] += 1;
+        }
+        histogram
+    }
+}
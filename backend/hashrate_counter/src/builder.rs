@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+use thiserror::Error;
+
+use crate::{GameOfLife, Neighborhood, Point, Rule, Topology, DEFAULT_STABILITY_WINDOW};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BuildError {
+    #[error("stability window must be at least 1")]
+    InvalidStabilityWindow,
+    #[error("variance window must be at least 2, got {0}")]
+    InvalidVarianceWindow(usize),
+    #[error("torus dimensions must be non-zero, got {width}x{height}")]
+    InvalidTopology { width: u32, height: u32 },
+    #[error("neighborhoods can have at most 255 neighbors, got {0}")]
+    NeighborhoodTooLarge(usize),
+    #[error("rule uses a neighbor count of {count}, but the neighborhood only has {max} neighbors")]
+    RuleOutOfRange { count: u8, max: usize },
+}
+
+#[derive(Clone, Debug)]
+pub struct GameOfLifeBuilder {
+    stability_window: usize,
+    max_population: Option<usize>,
+    variance_stop: Option<(f64, usize)>,
+    max_cells: Option<usize>,
+    rule: Rule,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    live_cells: HashSet<Point>,
+    spatial_index: bool,
+    track_ages: bool,
+    track_entropy: bool,
+    track_density: bool,
+    density_interval: usize,
+    track_quadrants: bool,
+    track_components: bool,
+    track_heatmap: bool,
+    birth_tracking: bool,
+    snapshot_at: HashSet<usize>,
+}
+
+impl Default for GameOfLifeBuilder {
+    fn default() -> Self {
+        GameOfLifeBuilder {
+            stability_window: DEFAULT_STABILITY_WINDOW,
+            max_population: None,
+            variance_stop: None,
+            max_cells: None,
+            rule: Rule::conway(),
+            topology: Topology::Infinite,
+            neighborhood: Neighborhood::Moore,
+            live_cells: HashSet::new(),
+            spatial_index: false,
+            track_ages: false,
+            track_entropy: false,
+            track_density: false,
+            density_interval: 1,
+            track_quadrants: false,
+            track_components: false,
+            track_heatmap: false,
+            birth_tracking: false,
+            snapshot_at: HashSet::new(),
+        }
+    }
+}
+
+impl GameOfLifeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stability_window(mut self, n: usize) -> Self {
+        self.stability_window = n;
+        self
+    }
+
+    pub fn stop_variance(mut self, threshold: f64, window: usize) -> Self {
+        self.variance_stop = Some((threshold, window));
+        self
+    }
+
+    pub fn max_population(mut self, max_population: usize) -> Self {
+        self.max_population = Some(max_population);
+        self
+    }
+
+    pub fn max_cells(mut self, max_cells: usize) -> Self {
+        self.max_cells = Some(max_cells);
+        self
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    pub fn topology(mut self, t: Topology) -> Self {
+        self.topology = t;
+        self
+    }
+
+    pub fn neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    pub fn live_cells(mut self, cells: HashSet<Point>) -> Self {
+        self.live_cells = cells;
+        self
+    }
+
+    pub fn spatial_index(mut self, enabled: bool) -> Self {
+        self.spatial_index = enabled;
+        self
+    }
+
+    pub fn track_ages(mut self, enabled: bool) -> Self {
+        self.track_ages = enabled;
+        self
+    }
+
+    pub fn track_entropy(mut self, enabled: bool) -> Self {
+        self.track_entropy = enabled;
+        self
+    }
+
+    pub fn track_density(mut self, enabled: bool) -> Self {
+        self.track_density = enabled;
+        self
+    }
+
+    pub fn density_interval(mut self, interval: usize) -> Self {
+        self.density_interval = interval;
+        self
+    }
+
+    pub fn track_quadrants(mut self, enabled: bool) -> Self {
+        self.track_quadrants = enabled;
+        self
+    }
+
+    pub fn track_components(mut self, enabled: bool) -> Self {
+        self.track_components = enabled;
+        self
+    }
+
+    pub fn track_heatmap(mut self, enabled: bool) -> Self {
+        self.track_heatmap = enabled;
+        self
+    }
+
+    pub fn birth_tracking(mut self, enabled: bool) -> Self {
+        self.birth_tracking = enabled;
+        self
+    }
+
+    pub fn snapshot_at(mut self, generations: HashSet<usize>) -> Self {
+        self.snapshot_at = generations;
+        self
+    }
+
+    pub fn build(self) -> Result<GameOfLife, BuildError> {
+        if self.stability_window == 0 {
+            return Err(BuildError::InvalidStabilityWindow);
+        }
+        if let Some((_, window)) = self.variance_stop.filter(|&(_, window)| window < 2) {
+            return Err(BuildError::InvalidVarianceWindow(window));
+        }
+        if let Topology::Torus { width, height } = self.topology {
+            if width == 0 || height == 0 {
+                return Err(BuildError::InvalidTopology { width, height });
+            }
+        }
+
+        let max_neighbors = self.neighborhood.checked_max_neighbors()?;
+        if let Some(count) = self.rule.max_count().filter(|&count| count as usize > max_neighbors) {
+            return Err(BuildError::RuleOutOfRange { count, max: max_neighbors });
+        }
+
+        let topology = self.topology;
+        let mut game = GameOfLife {
+            live_cells: self.live_cells.into_iter().map(|cell| topology.wrap(cell)).collect(),
+            stability_window: self.stability_window,
+            max_population: self.max_population,
+            variance_stop: self.variance_stop,
+            max_cells: self.max_cells,
+            rule: self.rule,
+            topology,
+            spatial_index: self.spatial_index,
+            track_ages: self.track_ages,
+            track_entropy: self.track_entropy,
+            track_density: self.track_density,
+            density_interval: self.density_interval.max(1),
+            track_quadrants: self.track_quadrants,
+            track_components: self.track_components,
+            track_heatmap: self.track_heatmap,
+            birth_tracking: self.birth_tracking,
+            snapshot_at: self.snapshot_at,
+            ..GameOfLife::new()
+        };
+        game.set_neighborhood(self.neighborhood)?;
+        Ok(game)
+    }
+}
+
+impl GameOfLife {
+    pub fn builder() -> GameOfLifeBuilder {
+        GameOfLifeBuilder::new()
+    }
+}
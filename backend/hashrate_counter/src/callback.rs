@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use crate::{GameOfLife, Point};
+
+pub(crate) type GenerationCallback = Arc<dyn Fn(usize, &HashSet<Point>) + Send + Sync>;
+
+impl GameOfLife {
+    // Called with the generation and its live cells after every step of any
+    // simulation run. The closure must be `Send + Sync` so games can still be
+    // simulated in parallel; `simulate_with` takes borrowing closures instead.
+    // Hashlife falls back to the naive stepper while a callback is set. Clones
+    // of the game share the callback.
+    pub fn set_generation_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(usize, &HashSet<Point>) + Send + Sync + 'static,
+    {
+        self.generation_callback = Some(Arc::new(callback));
+    }
+
+    pub fn clear_generation_callback(&mut self) {
+        self.generation_callback = None;
+    }
+
+    pub(crate) fn notify_generation(&self, generation: usize) {
+        if let Some(callback) = &self.generation_callback {
+            callback(generation, &self.live_cells);
+        }
+    }
+
+    // Like `simulate`, but calls `callback` after every step and returns the
+    // generation the run stabilized at, if any.
+    pub fn simulate_with<F>(&mut self, iterations: usize, mut callback: F) -> Option<usize>
+    where
+        F: FnMut(usize, &HashSet<Point>),
+    {
+        let result = self.run_observed(iterations, 0, Self::next_generation, |generation, cells| {
+            if generation > 0 {
+                callback(generation, cells);
+            }
+            Ok::<(), Infallible>(())
+        });
+        match result {
+            Ok(kind) => kind.generation(),
+            Err(never) => match never {},
+        }
+    }
+}
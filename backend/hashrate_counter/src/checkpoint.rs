@@ -0,0 +1,75 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{GameOfLife, Point, PowercelaError, StabilizationKind};
+
+#[derive(Serialize, Deserialize)]
+struct Checkpoint {
+    generation: usize,
+    #[serde(rename = "liveCells")]
+    live_cells: Vec<(i64, i64)>,
+    #[serde(rename = "generationData")]
+    generation_data: BTreeMap<usize, usize>,
+}
+
+// Written to a temporary file first, so a crash mid-write leaves the previous
+// checkpoint intact.
+fn write_checkpoint(
+    path: &Path,
+    generation: usize,
+    cells: &HashSet<Point>,
+    generation_data: &BTreeMap<usize, usize>,
+) -> Result<(), PowercelaError> {
+    let mut live_cells: Vec<(i64, i64)> = cells.iter().map(|cell| (cell.x, cell.y)).collect();
+    live_cells.sort_unstable();
+    let checkpoint = Checkpoint { generation, live_cells, generation_data: generation_data.clone() };
+
+    let temporary = path.with_extension("tmp");
+    let mut writer = BufWriter::new(File::create(&temporary)?);
+    serde_json::to_writer(&mut writer, &checkpoint)?;
+    writer.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+    fs::rename(&temporary, path)?;
+    Ok(())
+}
+
+impl GameOfLife {
+    // Only the cells, populations and generation are saved; rule, topology and
+    // stability window come from whoever resumes the run.
+    pub fn save_checkpoint(&self, path: &Path) -> Result<(), PowercelaError> {
+        write_checkpoint(path, self.generation, &self.live_cells, &self.generation_data)
+    }
+
+    pub fn load_checkpoint(path: &Path) -> Result<GameOfLife, PowercelaError> {
+        let checkpoint: Checkpoint = serde_json::from_reader(File::open(path)?)?;
+        let mut game = GameOfLife::new();
+        game.live_cells = checkpoint.live_cells.into_iter().map(|(x, y)| Point::new(x, y)).collect();
+        game.generation_data = checkpoint.generation_data;
+        game.generation = checkpoint.generation;
+        Ok(game)
+    }
+
+    // Like `simulate`, but saves a checkpoint to `path` every `every` generations.
+    pub fn simulate_checkpointed(
+        &mut self,
+        iterations: usize,
+        resume_from: Option<usize>,
+        every: usize,
+        path: &Path,
+    ) -> Result<StabilizationKind, PowercelaError> {
+        let every = every.max(1);
+        let mut generation_data = std::mem::take(&mut self.generation_data);
+        let result = self.run_with(iterations, resume_from.unwrap_or(0), Self::next_generation, |generation, cells| {
+            generation_data.insert(generation, cells.len());
+            if generation > 0 && generation.is_multiple_of(every) {
+                write_checkpoint(path, generation, cells, &generation_data)?;
+            }
+            Ok(())
+        });
+        self.generation_data = generation_data;
+        result
+    }
+}
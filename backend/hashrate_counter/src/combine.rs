@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+use crate::{GameOfLife, Point};
+
+impl GameOfLife {
+    pub fn overlay(&mut self, other: &GameOfLife) -> &mut Self {
+        self.live_cells.extend(other.live_cells.iter().copied());
+        self
+    }
+
+    pub fn intersect(&mut self, other: &GameOfLife) -> &mut Self {
+        self.live_cells.retain(|cell| other.live_cells.contains(cell));
+        self
+    }
+
+    pub fn subtract(&mut self, other: &GameOfLife) -> &mut Self {
+        self.live_cells.retain(|cell| !other.live_cells.contains(cell));
+        self
+    }
+
+    pub fn symmetric_difference(&mut self, other: &GameOfLife) -> &mut Self {
+        self.xor_overlay(&other.live_cells)
+    }
+
+    // Toggles every cell of `other`: the live cells become those in exactly
+    // one of the two sets.
+    pub fn xor_overlay(&mut self, other: &HashSet<Point>) -> &mut Self {
+        for &cell in other {
+            if !self.live_cells.remove(&cell) {
+                self.live_cells.insert(cell);
+            }
+        }
+        self
+    }
+}
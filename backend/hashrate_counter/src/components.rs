@@ -0,0 +1,88 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{GameOfLife, Point};
+
+// Disjoint sets over cell indices, with path halving and union by size.
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        UnionFind { parent: (0..len).collect(), size: vec![1; len] }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
+
+// Half of the Moore offsets; the other half is covered from the neighbor's side.
+const FORWARD: [(i64, i64); 4] = [(1, -1), (1, 0), (1, 1), (0, 1)];
+
+// Labels each live cell with its group of Moore-connected cells. Labels run
+// from 0 in order of each group's smallest cell (by x, then y). Neighbors do
+// not wrap around a torus.
+pub fn label_components(cells: &HashSet<Point>) -> HashMap<Point, usize> {
+    let mut sorted: Vec<Point> = cells.iter().copied().collect();
+    sorted.sort();
+    let index: HashMap<Point, usize> = sorted.iter().enumerate().map(|(i, &cell)| (cell, i)).collect();
+
+    let mut sets = UnionFind::new(sorted.len());
+    for (i, cell) in sorted.iter().enumerate() {
+        for (dx, dy) in FORWARD {
+            let neighbor = Point::new(cell.x.wrapping_add(dx), cell.y.wrapping_add(dy));
+            if let Some(&j) = index.get(&neighbor) {
+                sets.union(i, j);
+            }
+        }
+    }
+
+    let mut labels_by_root = HashMap::new();
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &cell)| {
+            let root = sets.find(i);
+            let next = labels_by_root.len();
+            (cell, *labels_by_root.entry(root).or_insert(next))
+        })
+        .collect()
+}
+
+pub fn count_components(cells: &HashSet<Point>) -> usize {
+    label_components(cells).values().copied().max().map_or(0, |label| label + 1)
+}
+
+impl GameOfLife {
+    pub fn track_components(&self) -> bool {
+        self.track_components
+    }
+
+    pub fn set_track_components(&mut self, enabled: bool) {
+        self.track_components = enabled;
+    }
+
+    pub(crate) fn record_components(&mut self, generation: usize) {
+        if self.track_components {
+            self.component_series.insert(generation, count_components(&self.live_cells));
+        }
+    }
+}
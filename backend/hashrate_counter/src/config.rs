@@ -0,0 +1,237 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::output::OutputFormat;
+use crate::stats::DEFAULT_VARIANCE_WINDOW;
+use crate::{GameOfLifeBuilder, Neighborhood, PowercelaError, RandomSpec, Rule, Topology};
+
+pub const DEFAULT_CONFIG_FILE: &str = "powercela.toml";
+
+// Written by `config --init`; every value shown is the default.
+pub const EXAMPLE_CONFIG: &str = r#"# powercela run configuration. Flags given on the command line override these values.
+
+iterations = 1000
+# A built-in pattern name, or a flat 0/1 string when split_amount is set.
+pattern = "glider"
+# split_amount = 9
+# Or start from a seeded random soup instead of a pattern.
+# random = "width=50,height=50,density=0.35,seed=42"
+stability_window = 50
+# Stop once the population grows past this.
+# max_population = 1000000
+# Fail once the live cells grow past this.
+# max_cells = 10000000
+# Stop once the variance of the last stop_variance_window populations is
+# below this.
+# stop_variance_threshold = 1.0
+# stop_variance_window = 100
+rule = "B3/S23"
+# "moore" or "vonneumann", optionally with a radius such as "moore:2".
+neighborhood = "moore"
+# "infinite", or "<width>x<height>" for a wrap-around grid.
+topology = "infinite"
+format = "json"
+compress = false
+parallel = false
+algorithm = "naive"
+# Move the starting pattern to a canonical position: "corner" or "center".
+# normalize = "corner"
+streaming = false
+track_ages = false
+track_entropy = false
+track_density = false
+# Measure the bounding box every N generations and interpolate in between.
+density_interval = 1
+track_quadrants = false
+track_components = false
+track_heatmap = false
+# The N cells alive in the most generations.
+# top_cells = 10
+# How many live cells at the end have 0 to 8 live neighbors.
+neighbor_distribution = false
+track_birth_gen = false
+# Generations whose live cells are included in the result.
+# snapshot = [0, 100, 500]
+include_deltas = false
+delta_window = 10
+# Autocorrelation of the population series for lags 0 to N.
+# autocorrelation = 64
+# Estimate the main period of the population from its spectrum.
+spectrum = false
+# output = "result/glider.json"
+"#;
+
+// Every simulation parameter the `run` subcommand accepts. Unset fields fall
+// back to the command line and then to the built-in defaults.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub iterations: Option<usize>,
+    pub pattern: Option<String>,
+    pub split_amount: Option<usize>,
+    #[serde(default, deserialize_with = "parse_field")]
+    pub random: Option<RandomSpec>,
+    pub stability_window: Option<usize>,
+    pub max_population: Option<usize>,
+    pub max_cells: Option<usize>,
+    pub stop_variance_threshold: Option<f64>,
+    pub stop_variance_window: Option<usize>,
+    #[serde(default, deserialize_with = "parse_field")]
+    pub rule: Option<Rule>,
+    #[serde(default, deserialize_with = "parse_field")]
+    pub neighborhood: Option<Neighborhood>,
+    #[serde(default, deserialize_with = "parse_field")]
+    pub topology: Option<Topology>,
+    #[serde(default, deserialize_with = "parse_field")]
+    pub format: Option<OutputFormat>,
+    pub compress: Option<bool>,
+    pub parallel: Option<bool>,
+    pub algorithm: Option<String>,
+    pub normalize: Option<String>,
+    pub streaming: Option<bool>,
+    pub track_ages: Option<bool>,
+    pub track_entropy: Option<bool>,
+    pub track_density: Option<bool>,
+    pub density_interval: Option<usize>,
+    pub track_quadrants: Option<bool>,
+    pub track_components: Option<bool>,
+    pub track_heatmap: Option<bool>,
+    pub top_cells: Option<usize>,
+    pub neighbor_distribution: Option<bool>,
+    pub track_birth_gen: Option<bool>,
+    pub snapshot: Option<Vec<usize>>,
+    pub include_deltas: Option<bool>,
+    pub delta_window: Option<usize>,
+    pub autocorrelation: Option<usize>,
+    pub spectrum: Option<bool>,
+    pub output: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Config, PowercelaError> {
+        let source = fs::read_to_string(path)?;
+        Config::parse(&source)
+    }
+
+    pub fn parse(source: &str) -> Result<Config, PowercelaError> {
+        toml::from_str(source).map_err(|e| PowercelaError::ParseError(format!("invalid config: {}", e)))
+    }
+
+    // `powercela.toml` in the working directory, or an empty config if there is none.
+    pub fn discover() -> Result<Config, PowercelaError> {
+        let path = Path::new(DEFAULT_CONFIG_FILE);
+        if path.exists() {
+            Config::load(path)
+        } else {
+            Ok(Config::default())
+        }
+    }
+
+    // Values set in `overrides` win; everything else is kept from `self`.
+    pub fn merge(self, overrides: Config) -> Config {
+        Config {
+            iterations: overrides.iterations.or(self.iterations),
+            pattern: overrides.pattern.or(self.pattern),
+            split_amount: overrides.split_amount.or(self.split_amount),
+            random: overrides.random.or(self.random),
+            stability_window: overrides.stability_window.or(self.stability_window),
+            max_population: overrides.max_population.or(self.max_population),
+            max_cells: overrides.max_cells.or(self.max_cells),
+            stop_variance_threshold: overrides.stop_variance_threshold.or(self.stop_variance_threshold),
+            stop_variance_window: overrides.stop_variance_window.or(self.stop_variance_window),
+            rule: overrides.rule.or(self.rule),
+            neighborhood: overrides.neighborhood.or(self.neighborhood),
+            topology: overrides.topology.or(self.topology),
+            format: overrides.format.or(self.format),
+            compress: overrides.compress.or(self.compress),
+            parallel: overrides.parallel.or(self.parallel),
+            algorithm: overrides.algorithm.or(self.algorithm),
+            normalize: overrides.normalize.or(self.normalize),
+            streaming: overrides.streaming.or(self.streaming),
+            track_ages: overrides.track_ages.or(self.track_ages),
+            track_entropy: overrides.track_entropy.or(self.track_entropy),
+            track_density: overrides.track_density.or(self.track_density),
+            density_interval: overrides.density_interval.or(self.density_interval),
+            track_quadrants: overrides.track_quadrants.or(self.track_quadrants),
+            track_components: overrides.track_components.or(self.track_components),
+            track_heatmap: overrides.track_heatmap.or(self.track_heatmap),
+            top_cells: overrides.top_cells.or(self.top_cells),
+            neighbor_distribution: overrides.neighbor_distribution.or(self.neighbor_distribution),
+            track_birth_gen: overrides.track_birth_gen.or(self.track_birth_gen),
+            snapshot: overrides.snapshot.or(self.snapshot),
+            include_deltas: overrides.include_deltas.or(self.include_deltas),
+            delta_window: overrides.delta_window.or(self.delta_window),
+            autocorrelation: overrides.autocorrelation.or(self.autocorrelation),
+            spectrum: overrides.spectrum.or(self.spectrum),
+            output: overrides.output.or(self.output),
+        }
+    }
+
+    pub fn builder(&self) -> GameOfLifeBuilder {
+        let mut builder = GameOfLifeBuilder::new();
+        if let Some(rule) = &self.rule {
+            builder = builder.rule(rule.clone());
+        }
+        if let Some(neighborhood) = &self.neighborhood {
+            builder = builder.neighborhood(neighborhood.clone());
+        }
+        if let Some(topology) = self.topology {
+            builder = builder.topology(topology);
+        }
+        if let Some(stability_window) = self.stability_window {
+            builder = builder.stability_window(stability_window);
+        }
+        if let Some(max_population) = self.max_population {
+            builder = builder.max_population(max_population);
+        }
+        if let Some(max_cells) = self.max_cells {
+            builder = builder.max_cells(max_cells);
+        }
+        if let Some(threshold) = self.stop_variance_threshold {
+            builder = builder.stop_variance(threshold, self.stop_variance_window.unwrap_or(DEFAULT_VARIANCE_WINDOW));
+        }
+        if let Some(track_ages) = self.track_ages {
+            builder = builder.track_ages(track_ages);
+        }
+        if let Some(track_entropy) = self.track_entropy {
+            builder = builder.track_entropy(track_entropy);
+        }
+        if let Some(track_density) = self.track_density {
+            builder = builder.track_density(track_density);
+        }
+        if let Some(density_interval) = self.density_interval {
+            builder = builder.density_interval(density_interval);
+        }
+        if let Some(track_quadrants) = self.track_quadrants {
+            builder = builder.track_quadrants(track_quadrants);
+        }
+        if let Some(track_components) = self.track_components {
+            builder = builder.track_components(track_components);
+        }
+        // The top cells come from the heatmap.
+        if self.track_heatmap.is_some() || self.top_cells.is_some() {
+            builder = builder.track_heatmap(self.track_heatmap.unwrap_or(false) || self.top_cells.is_some());
+        }
+        if let Some(track_birth_gen) = self.track_birth_gen {
+            builder = builder.birth_tracking(track_birth_gen);
+        }
+        if let Some(snapshot) = &self.snapshot {
+            builder = builder.snapshot_at(snapshot.iter().copied().collect());
+        }
+        builder
+    }
+}
+
+fn parse_field<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = String::deserialize(deserializer)?;
+    value.parse().map(Some).map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use crate::GameOfLife;
+
+impl GameOfLife {
+    pub fn bounding_box_area(&self) -> u64 {
+        self.bounding_box().map_or(0, |bbox| bbox.area())
+    }
+
+    pub fn track_density(&self) -> bool {
+        self.track_density
+    }
+
+    pub fn set_track_density(&mut self, enabled: bool) {
+        self.track_density = enabled;
+    }
+
+    pub fn density_interval(&self) -> usize {
+        self.density_interval
+    }
+
+    // Bounding boxes are only measured every `interval` generations; areas in
+    // between are interpolated. An interval of 0 is raised to 1.
+    pub fn set_density_interval(&mut self, interval: usize) {
+        self.density_interval = interval.max(1);
+    }
+
+    pub(crate) fn record_area(&mut self, generation: usize, always: bool) {
+        if self.track_density && (always || generation.is_multiple_of(self.density_interval)) {
+            self.bbox_areas.insert(generation, self.bounding_box_area());
+        }
+    }
+
+    // Population over bounding box area for every recorded generation, or 0.0
+    // where the grid is empty.
+    pub fn density_series(&self) -> BTreeMap<usize, f64> {
+        let mut areas = self.bbox_areas.clone();
+        areas.insert(self.generation, self.bounding_box_area());
+
+        self.generation_data
+            .iter()
+            .map(|(&generation, &population)| {
+                let area = match (areas.range(..=generation).next_back(), areas.range(generation..).next()) {
+                    (Some((&g0, &a0)), Some((&g1, &a1))) if g1 > g0 => {
+                        let t = (generation - g0) as f64 / (g1 - g0) as f64;
+                        a0 as f64 + (a1 as f64 - a0 as f64) * t
+                    }
+                    (Some((_, &area)), _) | (None, Some((_, &area))) => area as f64,
+                    (None, None) => 0.0,
+                };
+                let density = if area > 0.0 { population as f64 / area } else { 0.0 };
+                (generation, density)
+            })
+            .collect()
+    }
+}
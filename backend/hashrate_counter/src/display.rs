@@ -0,0 +1,61 @@
+use std::fmt;
+
+use crate::{BoundingBox, GameOfLife, Point};
+
+pub const DEFAULT_DISPLAY_LIMIT: (usize, usize) = (80, 24);
+
+impl GameOfLife {
+    pub fn display_limit(&self) -> (usize, usize) {
+        self.display_limit
+    }
+
+    // The most columns and rows of cells `Display` prints, at least 3 each.
+    pub fn set_display_limit(&mut self, columns: usize, rows: usize) {
+        self.display_limit = (columns.max(3), rows.max(3));
+    }
+}
+
+// A header line with the generation and population, then the bounding box with
+// `#` for live and `.` for dead cells. A box larger than `display_limit` is
+// cropped around its center, with a `…` column or row on each cut edge.
+impl fmt::Display for GameOfLife {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Generation {}, population {}", self.generation, self.live_cells.len())?;
+        let Some(bbox) = BoundingBox::from_cells(&self.live_cells) else {
+            return Ok(());
+        };
+        let (max_columns, max_rows) = self.display_limit;
+        let (left, columns, cropped_x) = visible(bbox.min_x, bbox.width(), bbox.center().x, max_columns);
+        let (top, rows, cropped_y) = visible(bbox.min_y, bbox.height(), bbox.center().y, max_rows);
+
+        let edge = |f: &mut fmt::Formatter<'_>| write!(f, "\n{}", "…".repeat(columns + 2 * cropped_x as usize));
+        if cropped_y {
+            edge(f)?;
+        }
+        for row in 0..rows as i64 {
+            let y = top.wrapping_add(row);
+            let line: String = (0..columns as i64)
+                .map(|column| if self.live_cells.contains(&Point::new(left.wrapping_add(column), y)) { '#' } else { '.' })
+                .collect();
+            if cropped_x {
+                write!(f, "\n…{}…", line)?;
+            } else {
+                write!(f, "\n{}", line)?;
+            }
+        }
+        if cropped_y {
+            edge(f)?;
+        }
+        Ok(())
+    }
+}
+
+// The first coordinate and number of cells to show along one axis, and whether
+// the axis is cropped, leaving room for the two `…` markers.
+fn visible(min: i64, size: u64, center: i64, limit: usize) -> (i64, usize, bool) {
+    if size <= limit as u64 {
+        return (min, size as usize, false);
+    }
+    let shown = limit - 2;
+    (center.wrapping_sub(shown as i64 / 2), shown, true)
+}
@@ -0,0 +1,40 @@
+// Wolfram's elementary cellular automata: one row of cells, each updated from
+// itself and its two neighbors, with the row's ends wrapping around.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellularAutomaton1D {
+    pub row: Vec<bool>,
+}
+
+impl CellularAutomaton1D {
+    pub fn new(row: Vec<bool>) -> Self {
+        CellularAutomaton1D { row }
+    }
+
+    // Advances one generation under `rule`, whose bit `4l + 2c + r` gives the
+    // next state of a cell with left neighbor l, itself c and right neighbor
+    // r, and returns the new row.
+    pub fn step_rule(&mut self, rule: u8) -> Vec<bool> {
+        let width = self.row.len();
+        let next = (0..width)
+            .map(|i| {
+                let left = self.row[(i + width - 1) % width] as u8;
+                let center = self.row[i] as u8;
+                let right = self.row[(i + 1) % width] as u8;
+                rule >> (left << 2 | center << 1 | right) & 1 == 1
+            })
+            .collect();
+        self.row = next;
+        self.row.clone()
+    }
+}
+
+// The space-time diagram: `initial` followed by `generations` more rows.
+pub fn simulate_1d(rule: u8, initial: Vec<bool>, generations: usize) -> Vec<Vec<bool>> {
+    let mut automaton = CellularAutomaton1D::new(initial);
+    let mut rows = Vec::with_capacity(generations + 1);
+    rows.push(automaton.row.clone());
+    for _ in 0..generations {
+        rows.push(automaton.step_rule(rule));
+    }
+    rows
+}
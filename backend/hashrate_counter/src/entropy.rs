@@ -0,0 +1,59 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{BoundingBox, GameOfLife, Point, Topology};
+
+pub const ENTROPY_TILE_SIZE: i64 = 8;
+
+// Shannon entropy, in bits, of how the live cells are spread over 8x8 tiles:
+// 0 when they all sit in one tile, log2(tiles) when every tile holds the same
+// number. On a torus pass its size so tiles line up with the grid from (0, 0);
+// with 0x0 the tiles start at the bounding box corner.
+pub fn generation_entropy(cells: &HashSet<Point>, grid_w: u32, grid_h: u32) -> f64 {
+    let origin = if grid_w > 0 && grid_h > 0 {
+        Point::new(0, 0)
+    } else {
+        match BoundingBox::from_cells(cells) {
+            Some(bbox) => Point::new(bbox.min_x, bbox.min_y),
+            None => return 0.0,
+        }
+    };
+
+    let mut tiles: HashMap<(i64, i64), usize> = HashMap::new();
+    for cell in cells {
+        let tile = (
+            cell.x.wrapping_sub(origin.x).div_euclid(ENTROPY_TILE_SIZE),
+            cell.y.wrapping_sub(origin.y).div_euclid(ENTROPY_TILE_SIZE),
+        );
+        *tiles.entry(tile).or_insert(0) += 1;
+    }
+
+    let total = cells.len() as f64;
+    tiles
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total;
+            p * (1.0 / p).log2()
+        })
+        .sum()
+}
+
+impl GameOfLife {
+    pub fn track_entropy(&self) -> bool {
+        self.track_entropy
+    }
+
+    pub fn set_track_entropy(&mut self, enabled: bool) {
+        self.track_entropy = enabled;
+    }
+
+    pub(crate) fn record_entropy(&mut self, generation: usize) {
+        if !self.track_entropy {
+            return;
+        }
+        let (grid_w, grid_h) = match self.topology() {
+            Topology::Torus { width, height } => (width, height),
+            Topology::Infinite => (0, 0),
+        };
+        self.entropy_series.insert(generation, generation_entropy(&self.live_cells, grid_w, grid_h));
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use crate::{BoundingBox, Point};
+
+// The symmetries of the square: four rotations, each optionally mirrored.
+const SYMMETRIES: [fn(Point) -> Point; 8] = [
+    |p| p,
+    |p| Point::new(p.y.wrapping_neg(), p.x),
+    |p| Point::new(p.x.wrapping_neg(), p.y.wrapping_neg()),
+    |p| Point::new(p.y, p.x.wrapping_neg()),
+    |p| Point::new(p.x.wrapping_neg(), p.y),
+    |p| Point::new(p.y, p.x),
+    |p| Point::new(p.x, p.y.wrapping_neg()),
+    |p| Point::new(p.y.wrapping_neg(), p.x.wrapping_neg()),
+];
+
+// Whether `b` is a translated copy of `a`. With `check_rotation`, rotated and
+// mirrored copies count too. Topology is ignored: cells are compared as plane
+// coordinates.
+pub fn patterns_equivalent(a: &HashSet<Point>, b: &HashSet<Point>, check_rotation: bool) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let target = normalized(b.iter().copied());
+    let symmetries = if check_rotation { &SYMMETRIES[..] } else { &SYMMETRIES[..1] };
+    symmetries.iter().any(|symmetry| normalized(a.iter().copied().map(symmetry)) == target)
+}
+
+// The cells shifted so the bounding box starts at (0, 0), sorted top to bottom
+// and left to right.
+fn normalized(cells: impl Iterator<Item = Point>) -> Vec<Point> {
+    let cells: Vec<Point> = cells.collect();
+    let Some(bbox) = BoundingBox::from_cells(&cells) else {
+        return cells;
+    };
+    let mut cells: Vec<Point> = cells
+        .into_iter()
+        .map(|cell| Point::new(cell.x.wrapping_sub(bbox.min_x), cell.y.wrapping_sub(bbox.min_y)))
+        .collect();
+    cells.sort_unstable_by_key(|cell| (cell.y, cell.x));
+    cells
+}
@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+use crate::{BuildError, ParseError as FormatParseError, RuleParseError, TopologyParseError, UnknownPattern};
+
+#[derive(Debug, Error)]
+pub enum PowercelaError {
+    #[error("invalid pattern: {0}")]
+    InvalidPattern(String),
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("parse error: {0}")]
+    ParseError(String),
+    #[error("image error: {0}")]
+    ImageError(#[from] image::ImageError),
+    #[error("Parquet error: {0}")]
+    ParquetError(#[from] parquet::errors::ParquetError),
+    #[error("invalid configuration: {0}")]
+    Build(#[from] BuildError),
+    #[error("telemetry error: {0}")]
+    Telemetry(String),
+    #[error("{count} live cells at generation {generation} exceed the cell limit")]
+    MemoryLimitExceeded { generation: usize, count: usize },
+}
+
+impl From<FormatParseError> for PowercelaError {
+    fn from(err: FormatParseError) -> Self {
+        PowercelaError::ParseError(err.to_string())
+    }
+}
+
+impl From<RuleParseError> for PowercelaError {
+    fn from(err: RuleParseError) -> Self {
+        PowercelaError::ParseError(err.to_string())
+    }
+}
+
+impl From<TopologyParseError> for PowercelaError {
+    fn from(err: TopologyParseError) -> Self {
+        PowercelaError::ParseError(err.to_string())
+    }
+}
+
+impl From<UnknownPattern> for PowercelaError {
+    fn from(err: UnknownPattern) -> Self {
+        PowercelaError::InvalidPattern(err.to_string())
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+
+use crate::{GameOfLife, Point};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimulationEvent {
+    CellBorn(Point),
+    CellDied(Point),
+}
+
+impl GameOfLife {
+    // Like `simulate_with`, but reports each cell that changed instead of the
+    // whole generation: births first, then deaths, each sorted top to bottom
+    // and left to right. Returns the generation the run stabilized at, if any.
+    pub fn simulate_events<F>(&mut self, iterations: usize, mut callback: F) -> Option<usize>
+    where
+        F: FnMut(usize, SimulationEvent),
+    {
+        let mut previous: HashSet<Point> = HashSet::new();
+        let result = self.run_observed(iterations, 0, Self::next_generation, |generation, cells| {
+            if generation > 0 {
+                let mut born: Vec<Point> = cells.difference(&previous).copied().collect();
+                let mut died: Vec<Point> = previous.difference(cells).copied().collect();
+                born.sort_unstable_by_key(|cell| (cell.y, cell.x));
+                died.sort_unstable_by_key(|cell| (cell.y, cell.x));
+                for cell in born {
+                    callback(generation, SimulationEvent::CellBorn(cell));
+                }
+                for cell in died {
+                    callback(generation, SimulationEvent::CellDied(cell));
+                }
+            }
+            previous.clone_from(cells);
+            Ok::<(), Infallible>(())
+        });
+        match result {
+            Ok(kind) => kind.generation(),
+            Err(never) => match never {},
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{BoundingBox, GameOfLife, Point};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl GameOfLife {
+    // Translations of the same cells get the same fingerprint. The hash is
+    // FNV-1a over the sorted, origin-shifted coordinates rather than
+    // `DefaultHasher`, whose output may change between Rust releases.
+    pub fn pattern_fingerprint(&self) -> u64 {
+        let Some(bbox) = BoundingBox::from_cells(&self.live_cells) else {
+            return FNV_OFFSET_BASIS;
+        };
+        let mut cells: Vec<Point> = self
+            .live_cells
+            .iter()
+            .map(|cell| Point::new(cell.x.wrapping_sub(bbox.min_x), cell.y.wrapping_sub(bbox.min_y)))
+            .collect();
+        cells.sort_unstable_by_key(|cell| (cell.y, cell.x));
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for cell in cells {
+            for byte in cell.x.to_le_bytes().into_iter().chain(cell.y.to_le_bytes()) {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash
+    }
+}
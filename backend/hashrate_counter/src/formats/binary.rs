@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::geometry::BoundingBox;
+use crate::Point;
+
+pub const BINARY_MAGIC: [u8; 4] = *b"PCLA";
+pub const BINARY_VERSION: u32 = 1;
+
+// The header is the magic followed by version, width, height, offset_x and
+// offset_y as little-endian 32-bit integers, 24 bytes in all.
+const HEADER_LEN: usize = 24;
+
+// Largest grid, in bytes, that `write_binary` will allocate: 2^31 cells.
+pub const MAX_GRID_BYTES: u64 = 1 << 28;
+
+// The cells' bounding box as a row-major bit grid, one bit per cell with the
+// most significant bit first, packed across rows and padded to a whole byte.
+// The offset is the box's top-left corner. Fails with `InvalidInput` when the
+// box does not fit in i32 coordinates or its grid is over MAX_GRID_BYTES.
+pub fn write_binary(cells: &HashSet<Point>, path: &Path) -> io::Result<()> {
+    let out_of_range = || io::Error::new(io::ErrorKind::InvalidInput, "pattern does not fit in i32 coordinates");
+    let (offset_x, offset_y, width, height) = match BoundingBox::from_cells(cells) {
+        Some(bbox) => {
+            let to_i32 = |value: i128| i32::try_from(value).map_err(|_| out_of_range());
+            let (min_x, min_y) = (bbox.min_x as i128, bbox.min_y as i128);
+            (to_i32(min_x)?, to_i32(min_y)?, to_i32(bbox.width() as i128)?, to_i32(bbox.height() as i128)?)
+        }
+        None => (0, 0, 0, 0),
+    };
+
+    let grid_len = grid_bytes(width, height);
+    if grid_len > MAX_GRID_BYTES {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("a {}x{} grid is too large", width, height)));
+    }
+    let mut bits = vec![0u8; grid_len as usize];
+    for cell in cells {
+        let index = (cell.y - offset_y as i64) as usize * width as usize + (cell.x - offset_x as i64) as usize;
+        bits[index / 8] |= 0x80 >> (index % 8);
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    writer.write_all(&BINARY_MAGIC)?;
+    writer.write_all(&BINARY_VERSION.to_le_bytes())?;
+    for field in [width, height, offset_x, offset_y] {
+        writer.write_all(&field.to_le_bytes())?;
+    }
+    writer.write_all(&bits)?;
+    writer.flush()
+}
+
+// Fails with `InvalidData` on a wrong magic or version, a negative size or a
+// grid shorter than the header promises. The grid is read as it arrives, so a
+// header claiming a huge grid fails once the file runs out rather than
+// allocating the whole grid up front.
+pub fn read_binary(path: &Path) -> io::Result<HashSet<Point>> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut header = [0u8; HEADER_LEN];
+    reader.read_exact(&mut header)?;
+    if header[..4] != BINARY_MAGIC {
+        return Err(invalid("not a PCLA file"));
+    }
+    let field = |index: usize| -> [u8; 4] { header[4 + index * 4..8 + index * 4].try_into().unwrap() };
+    let version = u32::from_le_bytes(field(0));
+    if version != BINARY_VERSION {
+        return Err(invalid(&format!("unsupported PCLA version {}", version)));
+    }
+    let [width, height, offset_x, offset_y] = [1, 2, 3, 4].map(|index| i32::from_le_bytes(field(index)));
+    if width < 0 || height < 0 {
+        return Err(invalid("negative grid size"));
+    }
+
+    let expected = grid_bytes(width, height);
+    let mut bits = Vec::new();
+    reader.take(expected).read_to_end(&mut bits)?;
+    if (bits.len() as u64) < expected {
+        return Err(invalid("grid is shorter than the header says"));
+    }
+
+    let cell_count = width as u64 * height as u64;
+    let width = width as usize;
+    let cells = bits
+        .iter()
+        .enumerate()
+        .filter(|(_, &byte)| byte != 0)
+        .flat_map(|(byte_index, &byte)| {
+            (0..8).filter(move |bit| byte & (0x80 >> bit) != 0).map(move |bit| byte_index * 8 + bit)
+        })
+        .take_while(|&index| (index as u64) < cell_count)
+        .map(|index| Point::new(offset_x as i64 + (index % width) as i64, offset_y as i64 + (index / width) as i64))
+        .collect();
+    Ok(cells)
+}
+
+fn grid_bytes(width: i32, height: i32) -> u64 {
+    (width as u64 * height as u64).div_ceil(8)
+}
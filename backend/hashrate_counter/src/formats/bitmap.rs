@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use crate::{GameOfLife, Point, PowercelaError};
+
+impl GameOfLife {
+    // Any image format the `image` crate was built with (PNG and BMP). Each
+    // pixel darker than `threshold` (luminance 0-255) becomes a live cell at
+    // its pixel coordinates, with the origin at the top-left corner and y
+    // growing downwards. A pixel must also have an alpha of at least 128:
+    // mostly transparent pixels stay dead whatever their luminance, so the
+    // transparent background of a drawing is not read as black.
+    pub fn load_from_image(&mut self, path: &Path, threshold: u8) -> Result<(), PowercelaError> {
+        let image = image::open(path)?.into_luma_alpha8();
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let [luminance, alpha] = pixel.0;
+            if luminance < threshold && alpha >= 128 {
+                self.live_cells.insert(self.topology().wrap(Point::new(x as i64, y as i64)));
+            }
+        }
+        Ok(())
+    }
+}
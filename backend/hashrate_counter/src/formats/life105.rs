@@ -0,0 +1,97 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+use crate::formats::ParseError;
+use crate::{BoundingBox, GameOfLife, Point, Rule};
+
+pub const LIFE105_HEADER: &str = "#Life 1.05";
+
+// Lines of `.`/`*` rows are kept to this many columns.
+const MAX_BLOCK_WIDTH: u64 = 80;
+
+// A `#P` block per 80 columns of the bounding box, each trimmed to the rows
+// and columns its cells use. `load_life105` reads the same cells back.
+pub fn export_life105(cells: &HashSet<Point>, name: &str) -> String {
+    let mut out = format!("{}\n#N {}\n", LIFE105_HEADER, name);
+    let Some(bbox) = BoundingBox::from_cells(cells) else {
+        return out;
+    };
+
+    let mut strips: BTreeMap<u64, Vec<Point>> = BTreeMap::new();
+    for &cell in cells {
+        strips.entry(cell.x.wrapping_sub(bbox.min_x) as u64 / MAX_BLOCK_WIDTH).or_default().push(cell);
+    }
+    for (strip, cells) in strips {
+        let origin_x = bbox.min_x.wrapping_add((strip * MAX_BLOCK_WIDTH) as i64);
+        let Some(block) = BoundingBox::from_cells(&cells) else {
+            continue;
+        };
+        let width = block.max_x.wrapping_sub(origin_x) as usize + 1;
+        let mut rows = vec![vec![b'.'; width]; block.height() as usize];
+        for cell in &cells {
+            rows[cell.y.wrapping_sub(block.min_y) as usize][cell.x.wrapping_sub(origin_x) as usize] = b'*';
+        }
+
+        let _ = writeln!(out, "#P {} {}", origin_x, block.min_y);
+        for row in rows {
+            out.push_str(&String::from_utf8_lossy(&row));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+impl GameOfLife {
+    // Life 1.05: `#P x y` starts a block of `.`/`*` rows at that offset, and a
+    // file may hold several blocks. The offsets already center the pattern, so
+    // nothing is shifted. `#N` selects Conway's rule and `#R` a survival/birth
+    // rule such as `#R 23/36`; `#D`, `#C` and other `#` lines are comments.
+    pub fn load_life105(&mut self, source: &str) -> Result<(), ParseError> {
+        let mut lines = source.lines().map(str::trim).peekable();
+        if let Some(header) = lines.peek().filter(|line| line.starts_with("#Life")) {
+            if header.split_whitespace().nth(1) != Some("1.05") {
+                return Err(ParseError::InvalidHeader(header.to_string()));
+            }
+            lines.next();
+        }
+
+        let mut cells = Vec::new();
+        let (mut origin_x, mut y) = (0i64, 0i64);
+        for line in lines {
+            if let Some(directive) = line.strip_prefix('#') {
+                let mut fields = directive.split_whitespace();
+                let invalid = || ParseError::InvalidHeader(line.to_string());
+                match fields.next() {
+                    Some("P") => {
+                        origin_x = fields.next().and_then(|x| x.parse().ok()).ok_or_else(invalid)?;
+                        y = fields.next().and_then(|y| y.parse().ok()).ok_or_else(invalid)?;
+                    }
+                    Some("N") => self.set_rule(Rule::conway()),
+                    Some("R") => {
+                        let rule = fields.next().ok_or_else(invalid)?;
+                        self.set_rule(Rule::parse_bs(rule).map_err(|_| invalid())?);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            for (x, ch) in line.chars().enumerate() {
+                match ch {
+                    '*' => cells.push(Point::new(origin_x.wrapping_add(x as i64), y)),
+                    '.' => {}
+                    c => return Err(ParseError::UnknownCharacter(c)),
+                }
+            }
+            y = y.wrapping_add(1);
+        }
+
+        for cell in cells {
+            self.live_cells.insert(self.topology().wrap(cell));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::formats::ParseError;
+use crate::{GameOfLife, Point};
+
+pub const LIFE106_HEADER: &str = "#Life 1.06";
+
+// One `x y` line per live cell, sorted top to bottom and left to right.
+pub fn export_life106(cells: &HashSet<Point>) -> String {
+    let mut cells: Vec<&Point> = cells.iter().collect();
+    cells.sort_by_key(|cell| (cell.y, cell.x));
+
+    let mut out = String::from(LIFE106_HEADER);
+    out.push('\n');
+    for cell in cells {
+        let _ = writeln!(out, "{} {}", cell.x, cell.y);
+    }
+    out
+}
+
+impl GameOfLife {
+    // Coordinates are used as given rather than centered. Blank lines are
+    // skipped; line numbers in errors count from 1.
+    pub fn load_life106(&mut self, source: &str) -> Result<(), ParseError> {
+        let mut lines = source.lines().enumerate();
+        match lines.next() {
+            Some((_, header)) if header.trim() == LIFE106_HEADER => {}
+            Some((_, header)) => return Err(ParseError::InvalidHeader(header.to_string())),
+            None => return Err(ParseError::InvalidHeader(String::new())),
+        }
+
+        let mut cells = Vec::new();
+        for (index, line) in lines {
+            let invalid = || ParseError::InvalidCoordinate(index + 1, line.to_string());
+            let mut fields = line.split_whitespace();
+            let (x, y) = match (fields.next(), fields.next(), fields.next()) {
+                (None, _, _) => continue,
+                (Some(x), Some(y), None) => (x, y),
+                _ => return Err(invalid()),
+            };
+            let x: i32 = x.parse().map_err(|_| invalid())?;
+            let y: i32 = y.parse().map_err(|_| invalid())?;
+            cells.push(Point::new(x as i64, y as i64));
+        }
+
+        for cell in cells {
+            self.live_cells.insert(self.topology().wrap(cell));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+pub mod binary;
+pub mod bitmap;
+pub mod life105;
+pub mod life106;
+pub mod rle;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("invalid header: {0}")]
+    InvalidHeader(String),
+    #[error("malformed run count: {0}")]
+    MalformedRunCount(String),
+    #[error("unknown character: {0:?}")]
+    UnknownCharacter(char),
+    #[error("missing '!' terminator")]
+    MissingTerminator,
+    #[error("live cells outside the {0}x{1} pattern size in the header")]
+    OutOfBounds(usize, usize),
+    #[error("invalid coordinate on line {0}: {1:?}")]
+    InvalidCoordinate(usize, String),
+}
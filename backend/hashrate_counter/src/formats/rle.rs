@@ -0,0 +1,249 @@
+use crate::formats::ParseError;
+use crate::{BoundingBox, GameOfLife, Point, Rule};
+
+// The RLE spec limits lines to 70 characters.
+const MAX_LINE_LENGTH: usize = 70;
+
+pub struct RlePattern {
+    pub width: usize,
+    pub height: usize,
+    pub rule: Option<String>,
+    // The top-left corner from a Golly `#CXRLE Pos=x,y` line.
+    pub position: Option<Point>,
+    // The starting generation from a Golly `#CXRLE Gen=n` line.
+    pub generation: Option<usize>,
+    // The first `#N` line.
+    pub name: Option<String>,
+    pub cells: Vec<Point>,
+}
+
+// What `GameOfLife::load_rle_extended` read from the comment lines besides the
+// cells. `pos_x` and `pos_y` are the corner the cells were placed at, so they
+// are set even without a `Pos=` field; `generation` is 0 without `Gen=`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RleMetadata {
+    pub pos_x: i64,
+    pub pos_y: i64,
+    pub generation: usize,
+    pub name: Option<String>,
+}
+
+pub fn parse_rle(source: &str) -> Result<RlePattern, ParseError> {
+    let cxrle_field = |key: &str| {
+        source
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("#CXRLE"))
+            .flat_map(str::split_whitespace)
+            .find_map(|field| field.strip_prefix(key).map(str::to_string))
+    };
+    let position = cxrle_field("Pos=")
+        .map(|pos| {
+            let invalid = || ParseError::InvalidHeader(format!("Pos={}", pos));
+            let (x, y) = pos.split_once(',').ok_or_else(invalid)?;
+            Ok(Point::new(x.parse().map_err(|_| invalid())?, y.parse().map_err(|_| invalid())?))
+        })
+        .transpose()?;
+    let generation = cxrle_field("Gen=")
+        .map(|generation| generation.parse().map_err(|_| ParseError::InvalidHeader(format!("Gen={}", generation))))
+        .transpose()?;
+    let name = source
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("#N"))
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+
+    let mut lines = source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+    let header = lines.next().ok_or(ParseError::InvalidHeader(String::new()))?;
+    let (width, height, rule) = parse_header(header)?;
+
+    let mut cells = Vec::new();
+    let mut run = String::new();
+    let (mut x, mut y) = (0i64, 0i64);
+    let (max_x, max_y) = (i64::try_from(width).unwrap_or(i64::MAX), i64::try_from(height).unwrap_or(i64::MAX));
+
+    // Rows may be wrapped across several lines, so the body is read as one stream.
+    for ch in lines.flat_map(str::chars) {
+        match ch {
+            '0'..='9' => run.push(ch),
+            'b' | 'o' | '$' => {
+                let count = run_count(&run)?;
+                run.clear();
+                match ch {
+                    'b' => x = x.saturating_add(count),
+                    'o' => {
+                        // Also keeps a huge run count from allocating without bound.
+                        if y >= max_y || x.saturating_add(count) > max_x {
+                            return Err(ParseError::OutOfBounds(width, height));
+                        }
+                        for _ in 0..count {
+                            cells.push(Point::new(x, y));
+                            x = x.saturating_add(1);
+                        }
+                    }
+                    _ => {
+                        y = y.saturating_add(count);
+                        x = 0;
+                    }
+                }
+            }
+            '!' => {
+                if !run.is_empty() {
+                    return Err(ParseError::MalformedRunCount(run));
+                }
+                return Ok(RlePattern { width, height, rule, position, generation, name, cells });
+            }
+            c if c.is_whitespace() => {
+                if !run.is_empty() {
+                    return Err(ParseError::MalformedRunCount(run));
+                }
+            }
+            c => return Err(ParseError::UnknownCharacter(c)),
+        }
+    }
+
+    Err(ParseError::MissingTerminator)
+}
+
+fn parse_header(header: &str) -> Result<(usize, usize, Option<String>), ParseError> {
+    let mut width = None;
+    let mut height = None;
+    let mut rule = None;
+
+    // Rules in the count list form contain commas, so the rule runs to the end of the line.
+    let (dimensions, rule_field) = match header.find("rule") {
+        Some(index) => header.split_at(index),
+        None => (header, ""),
+    };
+    if let Some((_, value)) = rule_field.split_once('=') {
+        rule = Some(value.trim().to_string());
+    }
+
+    for field in dimensions.split(',').filter(|field| !field.trim().is_empty()) {
+        let (key, value) = field
+            .split_once('=')
+            .ok_or_else(|| ParseError::InvalidHeader(header.to_string()))?;
+        let value = value.trim();
+        match key.trim() {
+            "x" => width = value.parse().ok(),
+            "y" => height = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height, rule)),
+        _ => Err(ParseError::InvalidHeader(header.to_string())),
+    }
+}
+
+fn run_count(run: &str) -> Result<i64, ParseError> {
+    if run.is_empty() {
+        return Ok(1);
+    }
+    match run.parse::<i64>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(ParseError::MalformedRunCount(run.to_string())),
+    }
+}
+
+impl GameOfLife {
+    // Patterns are centered (or placed at the origin on a torus) unless the
+    // file gives a `#CXRLE Pos=` corner, as `export_rle` does.
+    pub fn load_rle(&mut self, source: &str) -> Result<(), ParseError> {
+        self.place_rle(parse_rle(source)?).map(|_| ())
+    }
+
+    // Like `load_rle` into a new game, also returning the Golly metadata.
+    pub fn load_rle_extended(source: &str) -> Result<(RleMetadata, Self), ParseError> {
+        let pattern = parse_rle(source)?;
+        let (generation, name) = (pattern.generation.unwrap_or(0), pattern.name.clone());
+        let mut game = Self::new();
+        let (pos_x, pos_y) = game.place_rle(pattern)?;
+        Ok((RleMetadata { pos_x, pos_y, generation, name }, game))
+    }
+
+    // Returns the corner the pattern was placed at.
+    fn place_rle(&mut self, pattern: RlePattern) -> Result<(i64, i64), ParseError> {
+        if let Some(rule) = &pattern.rule {
+            let rule = Rule::parse_bs(rule).map_err(|_| ParseError::InvalidHeader(rule.clone()))?;
+            self.set_rule(rule);
+        }
+        let (start_x, start_y) = match pattern.position {
+            Some(position) => (position.x, position.y),
+            None if self.topology().is_infinite() => {
+                (((pattern.width / 2) as i64).wrapping_neg(), ((pattern.height / 2) as i64).wrapping_neg())
+            }
+            None => (0, 0),
+        };
+
+        for cell in pattern.cells {
+            let cell = Point::new(start_x.wrapping_add(cell.x), start_y.wrapping_add(cell.y));
+            self.live_cells.insert(self.topology().wrap(cell));
+        }
+        Ok((start_x, start_y))
+    }
+
+    // Reading the result back with `load_rle` gives the same live cells.
+    pub fn export_rle(&self) -> String {
+        let bbox = BoundingBox::from_cells(&self.live_cells);
+        let (width, height) = bbox.map_or((0, 0), |bbox| (bbox.width(), bbox.height()));
+        let mut out = String::from("#C Generated by powercela\n");
+        if let Some(bbox) = bbox {
+            out.push_str(&format!("#CXRLE Pos={},{}\n", bbox.min_x, bbox.min_y));
+        }
+        out.push_str(&format!("x = {}, y = {}, rule = {}\n", width, height, self.rule()));
+
+        let mut cells: Vec<&Point> = self.live_cells.iter().collect();
+        cells.sort_by_key(|cell| (cell.y, cell.x));
+
+        let mut tokens = Vec::new();
+        if let Some(bbox) = bbox {
+            let (mut row, mut column) = (bbox.min_y, bbox.min_x);
+            let mut run = 0;
+            for cell in cells {
+                if run > 0 && (cell.y != row || cell.x != column) {
+                    tokens.push(token(run, 'o'));
+                    run = 0;
+                }
+                if cell.y != row {
+                    tokens.push(token(cell.y.wrapping_sub(row) as u64, '$'));
+                    row = cell.y;
+                    column = bbox.min_x;
+                }
+                if cell.x != column {
+                    tokens.push(token(cell.x.wrapping_sub(column) as u64, 'b'));
+                }
+                run += 1;
+                column = cell.x.wrapping_add(1);
+            }
+            if run > 0 {
+                tokens.push(token(run, 'o'));
+            }
+        }
+        tokens.push("!".to_string());
+
+        let mut line_length = 0;
+        for token in tokens {
+            if line_length > 0 && line_length + token.len() > MAX_LINE_LENGTH {
+                out.push('\n');
+                line_length = 0;
+            }
+            line_length += token.len();
+            out.push_str(&token);
+        }
+        out.push('\n');
+        out
+    }
+}
+
+fn token(count: u64, tag: char) -> String {
+    if count == 1 {
+        tag.to_string()
+    } else {
+        format!("{}{}", count, tag)
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+use powercela_core::StabilityTracker;
+use tracing::Level;
+
+use crate::stats::longest_stability_streak;
+use crate::{current_stability_score, GameOfLife, Neighborhood, Point, PopulationStats, Rule, RuleParseError, SimulationResult, StabilizationKind, Topology};
+
+// A Generations rule: births and survivals as in `Rule`, counting only fully
+// alive neighbors, plus the number of cell states. A live cell that does not
+// survive starts dying and moves one state further each generation until it
+// reaches `states` and is dead. Two states is an ordinary life-like rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenerationsRule {
+    pub rule: Rule,
+    pub states: u8,
+}
+
+impl GenerationsRule {
+    // Star Wars, B2/S345/C4.
+    pub fn star_wars() -> Self {
+        GenerationsRule { rule: Rule::parse_bs("B2/S345").expect("valid rule"), states: 4 }
+    }
+}
+
+// Golly notation, "B2/S345/C4", or the digit form birth/survival/states,
+// "2/345/4". Unlike the life-like digit form `Rule::parse_bs` reads ("23/3"),
+// this one lists births first.
+impl FromStr for GenerationsRule {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RuleParseError::InvalidFormat(s.to_string());
+        let parts: Vec<&str> = s.trim().split('/').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+        let states_at = parts
+            .iter()
+            .position(|part| part.starts_with(['C', 'c']))
+            .unwrap_or(parts.len() - 1);
+        let states = parts[states_at].trim_start_matches(['C', 'c']);
+        let states: u8 = states.parse().ok().filter(|&states| states >= 2).ok_or_else(invalid)?;
+
+        let mut life: Vec<&str> = parts.iter().enumerate().filter(|&(i, _)| i != states_at).map(|(_, part)| *part).collect();
+        if !life.iter().any(|part| part.starts_with(['B', 'b', 'S', 's'])) {
+            life.reverse();
+        }
+        let rule = Rule::parse_bs(&life.join("/"))?;
+        Ok(GenerationsRule { rule, states })
+    }
+}
+
+impl fmt::Display for GenerationsRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/C{}", self.rule, self.states)
+    }
+}
+
+// Cells map to their state: 1 is alive and 2 up to `states - 1` are dying.
+// Dead cells are not stored.
+pub struct GenerationsGame {
+    pub cells: HashMap<Point, u8>,
+    pub generation_data: BTreeMap<usize, usize>,
+    rule: GenerationsRule,
+    topology: Topology,
+    neighbor_offsets: Vec<(i64, i64)>,
+    stability_window: usize,
+    generation: usize,
+    quiet: bool,
+}
+
+impl GenerationsGame {
+    pub fn new(rule: GenerationsRule) -> Self {
+        GenerationsGame {
+            cells: HashMap::new(),
+            generation_data: BTreeMap::new(),
+            rule,
+            topology: Topology::Infinite,
+            neighbor_offsets: Neighborhood::Moore.offsets(),
+            stability_window: crate::DEFAULT_STABILITY_WINDOW,
+            generation: 0,
+            quiet: false,
+        }
+    }
+
+    // Starts from `game`'s live cells, all alive, keeping its topology,
+    // neighborhood, stability window and quiet setting.
+    pub fn from_game(game: &GameOfLife, rule: GenerationsRule) -> Self {
+        GenerationsGame {
+            cells: game.live_cells.iter().map(|&cell| (cell, 1)).collect(),
+            topology: game.topology(),
+            neighbor_offsets: game.neighbor_offsets.clone(),
+            stability_window: game.stability_window(),
+            quiet: game.quiet,
+            ..GenerationsGame::new(rule)
+        }
+    }
+
+    pub fn rule(&self) -> &GenerationsRule {
+        &self.rule
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn set_stability_window(&mut self, w: usize) {
+        self.stability_window = w.max(1);
+    }
+
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    // Stops like `GameOfLife::simulate`, on extinction, a fixed point or an
+    // oscillation, judged by the number of live and dying cells, which is also
+    // what `generation_data` records.
+    pub fn simulate_generations(&mut self, iterations: usize) -> StabilizationKind {
+        self.generation_data.insert(self.generation, self.cells.len());
+        let mut stability = StabilityTracker::new(self.stability_window, self.cells.len());
+        for current_gen in self.generation + 1..=iterations {
+            self.cells = self.next_generation();
+            self.generation = current_gen;
+            let population = self.cells.len();
+            self.generation_data.insert(current_gen, population);
+
+            if population == 0 {
+                self.log(Level::INFO, format_args!("Population went extinct at generation {}", current_gen));
+                return StabilizationKind::Extinction(current_gen);
+            }
+            if let Some(kind) = stability.observe(current_gen, population) {
+                self.log(Level::INFO, format_args!("{}", stability.describe(kind, population)));
+                return kind;
+            }
+        }
+        self.log(Level::INFO, format_args!("Simulation completed all {} iterations without stabilizing", iterations));
+        StabilizationKind::None
+    }
+
+    fn next_generation(&self) -> HashMap<Point, u8> {
+        let mut neighbor_counts: HashMap<Point, u8> = HashMap::new();
+        for (&cell, _) in self.cells.iter().filter(|&(_, &state)| state == 1) {
+            for &(dx, dy) in &self.neighbor_offsets {
+                let neighbor = self.topology.wrap(Point::new(cell.x.wrapping_add(dx), cell.y.wrapping_add(dy)));
+                *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+
+        let mut next = HashMap::new();
+        for (&cell, &state) in &self.cells {
+            let count = neighbor_counts.get(&cell).copied().unwrap_or(0);
+            let next_state = if state == 1 && self.rule.rule.survival.contains(&count) { 1 } else { state + 1 };
+            if next_state < self.rule.states {
+                next.insert(cell, next_state);
+            }
+        }
+        for (cell, count) in neighbor_counts {
+            if self.rule.rule.birth.contains(&count) && !self.cells.contains_key(&cell) {
+                next.insert(cell, 1);
+            }
+        }
+        next
+    }
+
+    fn log(&self, level: Level, message: std::fmt::Arguments) {
+        if !self.quiet {
+            crate::log_at(level, message);
+        }
+    }
+}
+
+impl SimulationResult {
+    // `fingerprint` has to be taken before the run, from the starting pattern.
+    pub fn from_generations(game: GenerationsGame, stabilization_kind: StabilizationKind, fingerprint: u64) -> Self {
+        let (longest_stability_streak, streak_start_generation) = longest_stability_streak(&game.generation_data);
+        let populations: Vec<usize> = game.generation_data.values().copied().collect();
+        SimulationResult {
+            final_stability_score: current_stability_score(&populations, game.stability_window),
+            stats: PopulationStats::from_generations(&game.generation_data),
+            longest_stability_streak,
+            streak_start_generation,
+            generations: game.generation_data,
+            generation_deltas: None,
+            stabilized_at: stabilization_kind.generation(),
+            stabilization_kind,
+            topology: game.topology,
+            stability_window: game.stability_window,
+            fingerprint,
+            offset: None,
+            ages: None,
+            deltas: None,
+            rolling_avg_growth: None,
+            autocorrelation: None,
+            dominant_period_estimate: None,
+            entropy_series: None,
+            density_series: None,
+            quadrant_series: None,
+            component_count_series: None,
+            heatmap: None,
+            top_cells: None,
+            neighbor_distribution_final: None,
+            oldest_cell_age: None,
+            age_histogram: None,
+            snapshots: None,
+            tagged_populations: None,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{GameOfLife, Point, PowercelaError};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BoundingBox {
+    pub min_x: i64,
+    pub max_x: i64,
+    pub min_y: i64,
+    pub max_y: i64,
+}
+
+impl BoundingBox {
+    pub fn from_cells<'a>(cells: impl IntoIterator<Item = &'a Point>) -> Option<Self> {
+        let mut cells = cells.into_iter();
+        let first = cells.next()?;
+        let mut bbox = BoundingBox {
+            min_x: first.x,
+            max_x: first.x,
+            min_y: first.y,
+            max_y: first.y,
+        };
+        for cell in cells {
+            bbox.min_x = bbox.min_x.min(cell.x);
+            bbox.max_x = bbox.max_x.max(cell.x);
+            bbox.min_y = bbox.min_y.min(cell.y);
+            bbox.max_y = bbox.max_y.max(cell.y);
+        }
+        Some(bbox)
+    }
+
+    pub fn width(&self) -> u64 {
+        (self.max_x as i128 - self.min_x as i128 + 1) as u64
+    }
+
+    pub fn height(&self) -> u64 {
+        (self.max_y as i128 - self.min_y as i128 + 1) as u64
+    }
+
+    pub fn area(&self) -> u64 {
+        self.width() * self.height()
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(
+            (self.min_x as i128 + self.max_x as i128).div_euclid(2) as i64,
+            (self.min_y as i128 + self.max_y as i128).div_euclid(2) as i64,
+        )
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min_x..=self.max_x).contains(&point.x) && (self.min_y..=self.max_y).contains(&point.y)
+    }
+}
+
+impl GameOfLife {
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        BoundingBox::from_cells(&self.live_cells)
+    }
+
+    pub fn center_of_mass(&self) -> Option<(f64, f64)> {
+        if self.live_cells.is_empty() {
+            return None;
+        }
+        let (sum_x, sum_y) = self
+            .live_cells
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), cell| (sx + cell.x as f64, sy + cell.y as f64));
+        let count = self.live_cells.len() as f64;
+        Some((sum_x / count, sum_y / count))
+    }
+
+    // Places the pattern so the bounding box of its live cells is centered on the origin.
+    pub fn initialize_pattern_centered(&mut self, pattern: &[&str]) -> Result<(), PowercelaError> {
+        let cells: Vec<Point> = pattern
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .filter(|&(_, ch)| ch == '1')
+                    .map(move |(x, _)| Point::new(x as i64, y as i64))
+            })
+            .collect();
+
+        match BoundingBox::from_cells(&cells) {
+            Some(bbox) => {
+                let start_x = -(bbox.min_x + bbox.max_x) / 2;
+                let start_y = -(bbox.min_y + bbox.max_y) / 2;
+                self.initialize_pattern(pattern, start_x, start_y)
+            }
+            None => self.initialize_pattern(pattern, 0, 0),
+        }
+    }
+
+    // Centers the pattern's rows and columns, rather than its live cells, on
+    // the origin, as the binary does for `--pattern`. On a torus the pattern
+    // starts at (0, 0) instead.
+    pub fn initialize_pattern_at_origin(&mut self, pattern: &[&str]) -> Result<(), PowercelaError> {
+        if !self.topology().is_infinite() {
+            return self.initialize_pattern(pattern, 0, 0);
+        }
+        let width = pattern.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+        self.initialize_pattern(pattern, -(width as i64 / 2), -(pattern.len() as i64 / 2))
+    }
+
+    // Moves the live cells so the bounding box starts at (0, 0). Returns the
+    // offset that was added, so the original position is `cell - offset`.
+    pub fn normalize(&mut self) -> (i64, i64) {
+        let Some(bbox) = self.bounding_box() else {
+            return (0, 0);
+        };
+        let offset = (bbox.min_x.wrapping_neg(), bbox.min_y.wrapping_neg());
+        self.translate(offset.0, offset.1);
+        offset
+    }
+
+    // Like `normalize`, but centers the bounding box on the origin.
+    pub fn normalize_centered(&mut self) -> (i64, i64) {
+        let Some(bbox) = self.bounding_box() else {
+            return (0, 0);
+        };
+        let center = bbox.center();
+        let offset = (center.x.wrapping_neg(), center.y.wrapping_neg());
+        self.translate(offset.0, offset.1);
+        offset
+    }
+
+    // Moves every live cell by (dx, dy), wrapping on a torus.
+    pub fn translate(&mut self, dx: i64, dy: i64) -> &mut Self {
+        let topology = self.topology();
+        self.live_cells = std::mem::take(&mut self.live_cells)
+            .into_iter()
+            .map(|p| topology.wrap(Point::new(p.x.wrapping_add(dx), p.y.wrapping_add(dy))))
+            .collect();
+        self
+    }
+
+    pub fn rotate_90(&mut self) -> &mut Self {
+        self.transform(|p| Point::new(p.y.wrapping_neg(), p.x))
+    }
+
+    pub fn rotate_180(&mut self) -> &mut Self {
+        self.transform(|p| Point::new(p.x.wrapping_neg(), p.y.wrapping_neg()))
+    }
+
+    pub fn rotate_270(&mut self) -> &mut Self {
+        self.transform(|p| Point::new(p.y, p.x.wrapping_neg()))
+    }
+
+    pub fn flip_horizontal(&mut self) -> &mut Self {
+        self.transform(|p| Point::new(p.x.wrapping_neg(), p.y))
+    }
+
+    pub fn flip_vertical(&mut self) -> &mut Self {
+        self.transform(|p| Point::new(p.x, p.y.wrapping_neg()))
+    }
+
+    // Applies `f` to every live cell, then shifts the result so the bounding box
+    // keeps the center it had before the transform.
+    fn transform(&mut self, f: impl Fn(Point) -> Point) -> &mut Self {
+        let Some(before) = self.bounding_box() else {
+            return self;
+        };
+        let transformed: Vec<Point> = self.live_cells.drain().map(f).collect();
+        let Some(after) = BoundingBox::from_cells(&transformed) else {
+            return self;
+        };
+
+        let (old_center, new_center) = (before.center(), after.center());
+        let (dx, dy) = (old_center.x.wrapping_sub(new_center.x), old_center.y.wrapping_sub(new_center.y));
+        let topology = self.topology();
+        self.live_cells = transformed
+            .into_iter()
+            .map(|p| topology.wrap(Point::new(p.x.wrapping_add(dx), p.y.wrapping_add(dy))))
+            .collect();
+        self
+    }
+}
@@ -0,0 +1,96 @@
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr};
+
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+use tokio::task;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+use tracing::info;
+
+use crate::{limits, GameOfLife, PatternLibrary, PowercelaError};
+
+// The code `build.rs` generates from proto/powercela.proto.
+pub mod proto {
+    tonic::include_proto!("powercela");
+}
+
+use proto::simulator_server::{Simulator, SimulatorServer};
+use proto::{Empty, GenerationEvent, Pattern, PatternList, RunRequest};
+
+pub use proto::simulator_client::SimulatorClient;
+
+pub use crate::limits::{MAX_CELLS, MAX_ITERATIONS};
+
+// Events a slow client may have queued before the simulation waits for it.
+const EVENT_BUFFER: usize = 64;
+
+// Implements `Simulator`; each `Run` steps its own `GameOfLife` on the
+// blocking thread pool.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SimulatorService;
+
+#[tonic::async_trait]
+impl Simulator for SimulatorService {
+    type RunStream = ReceiverStream<Result<GenerationEvent, Status>>;
+
+    async fn run(&self, request: Request<RunRequest>) -> Result<Response<Self::RunStream>, Status> {
+        let request = request.into_inner();
+        let game = build_game(&request).map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let (sender, receiver) = mpsc::channel(EVENT_BUFFER);
+        task::spawn_blocking(move || stream_run(game, request.iterations as usize, &sender));
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn get_patterns(&self, _request: Request<Empty>) -> Result<Response<PatternList>, Status> {
+        let patterns = PatternLibrary::all()
+            .iter()
+            .map(|pattern| Pattern { name: pattern.name.to_string(), width: pattern.width as u64, height: pattern.height() as u64 });
+        Ok(Response::new(PatternList { patterns: patterns.collect() }))
+    }
+}
+
+// Serves `SimulatorService` on 127.0.0.1:`port` until the process is stopped.
+pub fn serve(port: u16) -> Result<(), PowercelaError> {
+    let runtime = Runtime::new()?;
+    runtime.block_on(async {
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+        info!("Serving gRPC on {}", address);
+        tonic::transport::Server::builder()
+            .add_service(SimulatorServer::new(SimulatorService))
+            .serve(address)
+            .await
+            .map_err(|e| PowercelaError::IoError(io::Error::other(e)))
+    })
+}
+
+// `pattern_flat` is always a flat string, and an empty `rule` means B3/S23.
+// On 32-bit targets, iterations too large for a `usize` fail the limit check
+// rather than wrap.
+fn build_game(request: &RunRequest) -> Result<GameOfLife, PowercelaError> {
+    let split_amount = usize::try_from(request.split_amount).unwrap_or(usize::MAX);
+    let rule = Some(request.rule.as_str()).filter(|rule| !rule.is_empty());
+    let iterations = usize::try_from(request.iterations).unwrap_or(usize::MAX);
+    limits::build_game(&request.pattern_flat, Some(split_amount), rule, iterations)
+}
+
+// Each event is sent once the next generation is known, so the last one can
+// say whether the run ended by stabilizing. Stops early when the client goes
+// away.
+fn stream_run(mut game: GameOfLife, iterations: usize, sender: &mpsc::Sender<Result<GenerationEvent, Status>>) {
+    let mut pending: Option<GenerationEvent> = None;
+    let outcome = game.simulate_observed(iterations, |generation, cells| {
+        if let Some(event) = pending.replace(GenerationEvent {
+            generation: generation as u64,
+            population: cells.len() as u64,
+            stabilized: false,
+        }) {
+            sender.blocking_send(Ok(event)).map_err(drop)?;
+        }
+        Ok::<(), ()>(())
+    });
+    if let (Ok(kind), Some(mut event)) = (outcome, pending) {
+        event.stabilized = kind.is_settled();
+        let _ = sender.blocking_send(Ok(event));
+    }
+}
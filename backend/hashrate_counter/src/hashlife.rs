@@ -0,0 +1,397 @@
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::rc::Rc;
+
+use thiserror::Error;
+use tracing::Level;
+
+use crate::{GameOfLife, Point, Rule, StabilizationKind};
+
+// A square quadtree node of side 2^level. Level 0 nodes are single cells;
+// nodes are canonicalized so identical subtrees share one allocation.
+#[derive(Debug)]
+pub struct Node {
+    id: usize,
+    level: u32,
+    population: u64,
+    children: Option<[Rc<Node>; 4]>,
+}
+
+impl Node {
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn population(&self) -> u64 {
+        self.population
+    }
+
+    fn nw(&self) -> &Rc<Node> {
+        &self.children.as_ref().expect("branch node")[0]
+    }
+
+    fn ne(&self) -> &Rc<Node> {
+        &self.children.as_ref().expect("branch node")[1]
+    }
+
+    fn sw(&self) -> &Rc<Node> {
+        &self.children.as_ref().expect("branch node")[2]
+    }
+
+    fn se(&self) -> &Rc<Node> {
+        &self.children.as_ref().expect("branch node")[3]
+    }
+}
+
+// Keeps the root's half-width (2^(level - 1)) comfortably inside i64.
+const MAX_LEVEL: u32 = 62;
+
+// The largest jump, 2^MAX_STEP generations, a root of `MAX_LEVEL` can make.
+// `advance` and `simulate_hashlife` split longer ones into jumps this size.
+pub const MAX_STEP: u32 = MAX_LEVEL - 3;
+
+// The pattern would spread outside the largest universe, 2^62 cells wide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the pattern outgrew the hashlife universe at generation {generation}")]
+pub struct UniverseFull {
+    pub generation: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum NodeKey {
+    Leaf(bool),
+    Branch([usize; 4]),
+}
+
+pub struct HashLifeEngine {
+    rule: Rule,
+    root: Rc<Node>,
+    generation: u64,
+    nodes: HashMap<NodeKey, Rc<Node>>,
+    empty: Vec<Rc<Node>>,
+    results: HashMap<(usize, u32), Rc<Node>>,
+}
+
+impl HashLifeEngine {
+    // Cells further than 2^60 from the origin are outside the largest universe and are dropped.
+    pub fn new(cells: &HashSet<Point>, rule: Rule) -> Self {
+        let mut engine = HashLifeEngine {
+            rule,
+            root: Rc::new(Node { id: 0, level: 0, population: 0, children: None }),
+            generation: 0,
+            nodes: HashMap::new(),
+            empty: Vec::new(),
+            results: HashMap::new(),
+        };
+        engine.root = engine.empty_node(3);
+        for &cell in cells {
+            let (x, y) = (cell.x, cell.y);
+            while !engine.covers(x, y) && engine.root.level < MAX_LEVEL - 1 {
+                engine.root = engine.expand(&engine.root.clone());
+            }
+            if engine.covers(x, y) {
+                engine.root = engine.set_cell(&engine.root.clone(), x, y);
+            }
+        }
+        engine
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn population(&self) -> u64 {
+        self.root.population
+    }
+
+    pub fn root(&self) -> &Rc<Node> {
+        &self.root
+    }
+
+    // Advances the universe by 2^k generations in one memoized recursive step.
+    // Fails without advancing when that needs a root above `MAX_LEVEL`: for
+    // any k above `MAX_STEP`, or when the pattern has spread too far.
+    pub fn step(&mut self, k: u32) -> Result<Rc<Node>, UniverseFull> {
+        loop {
+            let root = self.root.clone();
+            let settled = root.level >= k + 3 && {
+                let inner = self.center(&root);
+                self.center(&inner).population == root.population
+            };
+            if settled {
+                break;
+            }
+            if root.level >= MAX_LEVEL {
+                return Err(UniverseFull { generation: self.generation });
+            }
+            self.root = self.expand(&root);
+        }
+        let root = self.root.clone();
+        self.root = self.successor(&root, k);
+        self.generation += 1 << k;
+        Ok(self.root.clone())
+    }
+
+    // Advances by an arbitrary number of generations, one power of two at a
+    // time, stopping at the first step that fails.
+    pub fn advance(&mut self, generations: u64) -> Result<(), UniverseFull> {
+        for k in jumps(generations) {
+            self.step(k)?;
+        }
+        Ok(())
+    }
+
+    pub fn live_cells(&self) -> HashSet<Point> {
+        let mut cells = HashSet::new();
+        let half = 1i64 << (self.root.level - 1);
+        collect_cells(&self.root, -half, -half, &mut cells);
+        cells
+    }
+
+    pub fn into_live_cells(self) -> HashSet<Point> {
+        self.live_cells()
+    }
+
+    fn leaf(&mut self, alive: bool) -> Rc<Node> {
+        let next_id = self.nodes.len() + 1;
+        self.nodes
+            .entry(NodeKey::Leaf(alive))
+            .or_insert_with(|| {
+                Rc::new(Node {
+                    id: next_id,
+                    level: 0,
+                    population: alive as u64,
+                    children: None,
+                })
+            })
+            .clone()
+    }
+
+    fn join(&mut self, nw: Rc<Node>, ne: Rc<Node>, sw: Rc<Node>, se: Rc<Node>) -> Rc<Node> {
+        let key = NodeKey::Branch([nw.id, ne.id, sw.id, se.id]);
+        if let Some(node) = self.nodes.get(&key) {
+            return node.clone();
+        }
+        let node = Rc::new(Node {
+            id: self.nodes.len() + 1,
+            level: nw.level + 1,
+            population: nw.population + ne.population + sw.population + se.population,
+            children: Some([nw, ne, sw, se]),
+        });
+        self.nodes.insert(key, node.clone());
+        node
+    }
+
+    fn empty_node(&mut self, level: u32) -> Rc<Node> {
+        while self.empty.len() <= level as usize {
+            let node = match self.empty.last().cloned() {
+                None => self.leaf(false),
+                Some(e) => self.join(e.clone(), e.clone(), e.clone(), e),
+            };
+            self.empty.push(node);
+        }
+        self.empty[level as usize].clone()
+    }
+
+    fn covers(&self, x: i64, y: i64) -> bool {
+        let half = 1i64 << (self.root.level - 1);
+        (-half..half).contains(&x) && (-half..half).contains(&y)
+    }
+
+    // Wraps `node` in a border of empty space, doubling its side while keeping it centered.
+    fn expand(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        let e = self.empty_node(node.level - 1);
+        let nw = self.join(e.clone(), e.clone(), e.clone(), node.nw().clone());
+        let ne = self.join(e.clone(), e.clone(), node.ne().clone(), e.clone());
+        let sw = self.join(e.clone(), node.sw().clone(), e.clone(), e.clone());
+        let se = self.join(node.se().clone(), e.clone(), e.clone(), e);
+        self.join(nw, ne, sw, se)
+    }
+
+    fn set_cell(&mut self, node: &Rc<Node>, x: i64, y: i64) -> Rc<Node> {
+        if node.level == 0 {
+            return self.leaf(true);
+        }
+        let [mut nw, mut ne, mut sw, mut se] = node.children.clone().expect("branch node");
+        let offset = if node.level == 1 { 0 } else { 1i64 << (node.level - 2) };
+        let (west, north) = (x < 0, y < 0);
+        let cx = if node.level == 1 { 0 } else if west { x + offset } else { x - offset };
+        let cy = if node.level == 1 { 0 } else if north { y + offset } else { y - offset };
+        let child = match (west, north) {
+            (true, true) => &mut nw,
+            (false, true) => &mut ne,
+            (true, false) => &mut sw,
+            (false, false) => &mut se,
+        };
+        *child = self.set_cell(&child.clone(), cx, cy);
+        self.join(nw, ne, sw, se)
+    }
+
+    fn center(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        self.join(
+            node.nw().se().clone(),
+            node.ne().sw().clone(),
+            node.sw().ne().clone(),
+            node.se().nw().clone(),
+        )
+    }
+
+    // The central half of `node`, advanced by 2^j generations (j <= level - 2).
+    fn successor(&mut self, node: &Rc<Node>, j: u32) -> Rc<Node> {
+        if node.population == 0 {
+            return self.empty_node(node.level - 1);
+        }
+        if let Some(result) = self.results.get(&(node.id, j)) {
+            return result.clone();
+        }
+
+        let result = if node.level == 2 {
+            self.base_case(node)
+        } else {
+            let (nw, ne, sw, se) = (node.nw().clone(), node.ne().clone(), node.sw().clone(), node.se().clone());
+            let n01 = self.join(nw.ne().clone(), ne.nw().clone(), nw.se().clone(), ne.sw().clone());
+            let n10 = self.join(nw.sw().clone(), nw.se().clone(), sw.nw().clone(), sw.ne().clone());
+            let n11 = self.join(nw.se().clone(), ne.sw().clone(), sw.ne().clone(), se.nw().clone());
+            let n12 = self.join(ne.sw().clone(), ne.se().clone(), se.nw().clone(), se.ne().clone());
+            let n21 = self.join(sw.ne().clone(), se.nw().clone(), sw.se().clone(), se.sw().clone());
+            let nine = [nw, n01, ne, n10, n11, n12, sw, n21, se];
+
+            let full_speed = j == node.level - 2;
+            let r: Vec<Rc<Node>> = nine
+                .iter()
+                .map(|n| if full_speed { self.successor(n, j - 1) } else { self.center(n) })
+                .collect();
+            let inner_j = if full_speed { j - 1 } else { j };
+
+            let a = self.join(r[0].clone(), r[1].clone(), r[3].clone(), r[4].clone());
+            let b = self.join(r[1].clone(), r[2].clone(), r[4].clone(), r[5].clone());
+            let c = self.join(r[3].clone(), r[4].clone(), r[6].clone(), r[7].clone());
+            let d = self.join(r[4].clone(), r[5].clone(), r[7].clone(), r[8].clone());
+            let a = self.successor(&a, inner_j);
+            let b = self.successor(&b, inner_j);
+            let c = self.successor(&c, inner_j);
+            let d = self.successor(&d, inner_j);
+            self.join(a, b, c, d)
+        };
+
+        self.results.insert((node.id, j), result.clone());
+        result
+    }
+
+    // One generation of a 4x4 node, returning its central 2x2.
+    fn base_case(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        let mut grid = [[false; 4]; 4];
+        for (quadrant, (qx, qy)) in [(node.nw(), (0, 0)), (node.ne(), (2, 0)), (node.sw(), (0, 2)), (node.se(), (2, 2))] {
+            for (cell, (cx, cy)) in [(quadrant.nw(), (0, 0)), (quadrant.ne(), (1, 0)), (quadrant.sw(), (0, 1)), (quadrant.se(), (1, 1))] {
+                grid[qy + cy][qx + cx] = cell.population == 1;
+            }
+        }
+
+        let mut next = [false; 4];
+        for (i, (x, y)) in [(1, 1), (2, 1), (1, 2), (2, 2)].into_iter().enumerate() {
+            let mut neighbors = 0;
+            for dy in 0..3 {
+                for dx in 0..3 {
+                    if (dx, dy) != (1, 1) && grid[y + dy - 1][x + dx - 1] {
+                        neighbors += 1;
+                    }
+                }
+            }
+            next[i] = self.rule.next_state(grid[y][x], neighbors);
+        }
+
+        let cells: Vec<Rc<Node>> = next.iter().map(|&alive| self.leaf(alive)).collect();
+        self.join(cells[0].clone(), cells[1].clone(), cells[2].clone(), cells[3].clone())
+    }
+}
+
+// The exponents of the powers of two summing to `generations`, largest first,
+// with those above `MAX_STEP` split into several of `MAX_STEP`.
+fn jumps(generations: u64) -> impl Iterator<Item = u32> {
+    (0..u64::BITS).rev().filter(move |&k| generations & (1 << k) != 0).flat_map(capped)
+}
+
+// Like `jumps`, but first doubles the generation from 0 to 1, 2, 4, and so on
+// up to the largest power of two in `generations`, so a run lands on each of
+// them before it jumps the rest of the way.
+fn doubling_jumps(generations: u64) -> impl Iterator<Item = u32> {
+    let top = generations.checked_ilog2();
+    let doublings = top.map(|top| iter::once(0).chain(0..top)).into_iter().flatten();
+    let rest = top.map_or(0, |top| generations - (1 << top));
+    doublings.flat_map(capped).chain(jumps(rest))
+}
+
+fn capped(k: u32) -> impl Iterator<Item = u32> {
+    let step = k.min(MAX_STEP);
+    iter::repeat_n(step, 1 << (k - step))
+}
+
+fn collect_cells(node: &Node, x: i64, y: i64, cells: &mut HashSet<Point>) {
+    if node.population == 0 {
+        return;
+    }
+    match &node.children {
+        None => {
+            cells.insert(Point::new(x, y));
+        }
+        Some([nw, ne, sw, se]) => {
+            let half = 1i64 << (node.level - 1);
+            collect_cells(nw, x, y, cells);
+            collect_cells(ne, x + half, y, cells);
+            collect_cells(sw, x, y + half, cells);
+            collect_cells(se, x + half, y + half, cells);
+        }
+    }
+}
+
+impl GameOfLife {
+    // Records populations only at the checkpoints hashlife lands on, so
+    // stability is not checked: every power of two up to `iterations`, then
+    // after each of the remaining power-of-two jumps, e.g. 1, 2, 4, ..., 512,
+    // 768, 896, 960, 992 and 1000 for 1000 iterations. Extinction is reported
+    // at the first checkpoint with no live cells. Hashlife has no notion
+    // of wrap-around edges; on a torus this falls back to `simulate`, as it does
+    // for neighborhoods other than Moore and whenever every generation is
+    // needed: tracking cell ages, births, entropy, density or quadrants, taking
+    // snapshots or calling a generation callback. `max_population` and
+    // `max_cells` are only checked at the checkpoints too, so a run stops by at
+    // most twice the generation it passed the limit at. A pattern that outgrows
+    // the largest universe stops at the last checkpoint before that.
+    pub fn simulate_hashlife(&mut self, iterations: usize) -> StabilizationKind {
+        if !self.topology().is_infinite() || self.tracks_every_generation() || !self.neighborhood().is_moore() {
+            return self.simulate(iterations, None);
+        }
+
+        let mut engine = HashLifeEngine::new(&self.live_cells, self.rule().clone());
+        self.generation_data.insert(0, self.live_cells.len());
+        let mut kind = StabilizationKind::None;
+        for k in doubling_jumps(iterations as u64) {
+            if let Err(e) = engine.step(k) {
+                self.log(Level::WARN, format_args!("{}", e));
+                break;
+            }
+            let generation = engine.generation() as usize;
+            let population = engine.population() as usize;
+            self.generation_data.insert(generation, population);
+            self.log(Level::DEBUG, format_args!("Generation {}: Population = {}", generation, population));
+            if population == 0 {
+                self.log(Level::INFO, format_args!("Population went extinct at generation {}", generation));
+                kind = StabilizationKind::Extinction(generation);
+                break;
+            }
+            if self.max_cells().is_some_and(|max| population > max) {
+                self.log(Level::WARN, format_args!("Live cells exceeded the limit with {} at generation {}", population, generation));
+                kind = StabilizationKind::MemoryLimitExceeded { generation, count: population };
+                break;
+            }
+            if self.max_population().is_some_and(|max| population > max) {
+                self.log(Level::WARN, format_args!("Population exploded to {} at generation {}", population, generation));
+                kind = StabilizationKind::Explosion { generation, population };
+                break;
+            }
+        }
+        self.generation = engine.generation() as usize;
+        self.live_cells = engine.into_live_cells();
+        kind
+    }
+}
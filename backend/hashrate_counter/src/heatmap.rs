@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{GameOfLife, Point};
+
+// One entry of `SimulationResult::top_cells`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopCell {
+    pub x: i64,
+    pub y: i64,
+    pub alive_generations: usize,
+}
+
+// The heatmap as a JSON-friendly map from "x,y" to the number of generations
+// that cell was alive.
+pub fn heatmap_json(heatmap: &HashMap<Point, usize>) -> BTreeMap<String, usize> {
+    heatmap.iter().map(|(cell, &count)| (format!("{},{}", cell.x, cell.y), count)).collect()
+}
+
+impl GameOfLife {
+    pub fn track_heatmap(&self) -> bool {
+        self.track_heatmap
+    }
+
+    pub fn set_track_heatmap(&mut self, enabled: bool) {
+        self.track_heatmap = enabled;
+    }
+
+    // For every cell that was ever alive, the number of recorded generations
+    // it was alive in, the starting one included. Empty unless heatmap
+    // tracking was on during the run.
+    pub fn compute_heatmap(&self) -> HashMap<Point, usize> {
+        self.heatmap.clone()
+    }
+
+    // The `n` cells alive in the most generations, most first. Ties go to the
+    // smaller cell (by x, then y).
+    pub fn top_cells(&self, n: usize) -> Vec<(Point, usize)> {
+        let mut cells: Vec<(Point, usize)> = self.heatmap.iter().map(|(&cell, &count)| (cell, count)).collect();
+        cells.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        cells.truncate(n);
+        cells
+    }
+
+    pub(crate) fn record_heatmap(&mut self) {
+        if self.track_heatmap {
+            for &cell in &self.live_cells {
+                *self.heatmap.entry(cell).or_insert(0) += 1;
+            }
+        }
+    }
+}
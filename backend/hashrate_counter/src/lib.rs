@@ -0,0 +1,669 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::Infallible;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use powercela_core::StabilityTracker;
+use tracing::Level;
+
+pub mod ages;
+#[cfg(feature = "async")]
+pub mod async_steps;
+pub mod batch;
+pub mod births;
+pub mod builder;
+pub mod callback;
+pub mod checkpoint;
+pub mod combine;
+pub mod components;
+pub mod config;
+pub mod density;
+pub mod display;
+pub mod elementary;
+pub mod entropy;
+pub mod equivalence;
+pub mod error;
+pub mod events;
+pub mod fingerprint;
+pub mod formats;
+pub mod generations;
+pub mod geometry;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod hashlife;
+pub mod heatmap;
+#[cfg(any(feature = "server", feature = "grpc"))]
+pub mod limits;
+#[cfg(feature = "live-view")]
+pub mod live_view;
+pub mod mutate;
+pub mod neighborhood;
+pub mod output;
+pub mod patterns;
+pub mod perturb;
+pub mod progress;
+pub mod quadrants;
+pub mod random;
+pub mod raster;
+pub mod render;
+pub mod replay;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod snapshots;
+pub mod spatial;
+pub mod spectrum;
+pub mod stats;
+pub mod steps;
+pub mod stop;
+pub mod sweep;
+pub mod tags;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+pub mod tournament;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+
+pub use batch::batch_simulate;
+pub use builder::{BuildError, GameOfLifeBuilder};
+pub use config::Config;
+pub use elementary::{simulate_1d, CellularAutomaton1D};
+pub use equivalence::patterns_equivalent;
+pub use error::PowercelaError;
+pub use events::SimulationEvent;
+pub use formats::binary::{read_binary, write_binary};
+pub use formats::ParseError;
+pub use generations::{GenerationsGame, GenerationsRule};
+pub use geometry::BoundingBox;
+pub use hashlife::{HashLifeEngine, UniverseFull};
+pub use heatmap::TopCell;
+pub use neighborhood::{neighbor_count_distribution, Neighborhood};
+pub use perturb::{sensitivity_analysis, SensitivityReport};
+pub use powercela_core::{
+    current_stability_score, detect_phase, Point, Rule, RuleParseError, StabilizationKind, Topology, TopologyParseError,
+};
+pub use patterns::{NamedPattern, PatternCategory, PatternLibrary, PatternQuery, UnknownPattern};
+pub use progress::ProgressReporter;
+pub use random::RandomSpec;
+pub use spatial::SpatialIndex;
+pub use stats::{AgeStats, PopulationStats};
+pub use steps::{GenerationSnapshot, Steps};
+pub use stop::StopReason;
+pub use sweep::rule_sweep;
+pub use tournament::{tournament, tournament_by, PatternId, TournamentResult, WinCriterion};
+
+#[derive(Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub generations: BTreeMap<usize, usize>,
+    // Filled in by `include_deltas`: `deltas` with the first generation's
+    // whole population as its entry, so a running sum gives `generations`.
+    #[serde(rename = "generationDeltas", skip_serializing_if = "Option::is_none")]
+    pub generation_deltas: Option<BTreeMap<usize, i64>>,
+    #[serde(rename = "stabilizedAt")]
+    pub stabilized_at: Option<usize>,
+    #[serde(rename = "stabilizationKind")]
+    pub stabilization_kind: StabilizationKind,
+    #[serde(default, skip_serializing_if = "Topology::is_infinite")]
+    pub topology: Topology,
+    #[serde(rename = "stabilityWindow")]
+    pub stability_window: usize,
+    pub stats: PopulationStats,
+    // `stats::longest_stability_streak` of `generations`.
+    #[serde(rename = "longestStabilityStreak", default)]
+    pub longest_stability_streak: usize,
+    #[serde(rename = "streakStartGeneration", default)]
+    pub streak_start_generation: usize,
+    // `current_stability_score` of `generations` at the end of the run: 1.0
+    // once the population held for the whole stability window.
+    #[serde(rename = "finalStabilityScore", default)]
+    pub final_stability_score: f64,
+    // `GameOfLife::pattern_fingerprint` of the starting pattern.
+    pub fingerprint: u64,
+    // What `normalize` or `normalize_centered` added to the starting cells.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<(i64, i64)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ages: Option<AgeStats>,
+    // Filled in by `include_deltas`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deltas: Option<BTreeMap<usize, i64>>,
+    #[serde(rename = "rollingAvgGrowth", skip_serializing_if = "Option::is_none")]
+    pub rolling_avg_growth: Option<BTreeMap<usize, f64>>,
+    // Filled in by `include_autocorrelation`, indexed by lag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autocorrelation: Option<Vec<f64>>,
+    // Filled in by `include_spectrum`.
+    #[serde(rename = "dominantPeriodEstimate", skip_serializing_if = "Option::is_none")]
+    pub dominant_period_estimate: Option<f64>,
+    #[serde(rename = "entropySeries", skip_serializing_if = "Option::is_none")]
+    pub entropy_series: Option<BTreeMap<usize, f64>>,
+    #[serde(rename = "densitySeries", skip_serializing_if = "Option::is_none")]
+    pub density_series: Option<BTreeMap<usize, f64>>,
+    #[serde(rename = "quadrantSeries", skip_serializing_if = "Option::is_none")]
+    pub quadrant_series: Option<BTreeMap<usize, [usize; 4]>>,
+    #[serde(rename = "componentCountSeries", skip_serializing_if = "Option::is_none")]
+    pub component_count_series: Option<BTreeMap<usize, usize>>,
+    // `heatmap::heatmap_json` of `GameOfLife::compute_heatmap`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heatmap: Option<BTreeMap<String, usize>>,
+    // `GameOfLife::top_cells`, set by the binary for `--top-cells`.
+    #[serde(rename = "topCells", skip_serializing_if = "Option::is_none")]
+    pub top_cells: Option<Vec<TopCell>>,
+    // `neighbor_count_distribution` of the last generation, set by the binary
+    // for `--neighbor-distribution`.
+    #[serde(rename = "neighborDistributionFinal", skip_serializing_if = "Option::is_none")]
+    pub neighbor_distribution_final: Option<[usize; 9]>,
+    #[serde(rename = "oldestCellAge", skip_serializing_if = "Option::is_none")]
+    pub oldest_cell_age: Option<usize>,
+    #[serde(rename = "ageHistogram", skip_serializing_if = "Option::is_none")]
+    pub age_histogram: Option<Vec<usize>>,
+    // Live cells as `[x, y]` pairs at the generations asked for with `set_snapshot_at`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshots: Option<BTreeMap<usize, Vec<[i64; 2]>>>,
+    // Per tag from `load_tagged_pattern`, the live cells on its starting cells.
+    #[serde(rename = "taggedPopulations", skip_serializing_if = "Option::is_none")]
+    pub tagged_populations: Option<BTreeMap<String, BTreeMap<usize, usize>>>,
+}
+
+impl SimulationResult {
+    // `fingerprint` has to be taken before the run, from the starting pattern.
+    pub fn from_game(game: GameOfLife, stabilization_kind: StabilizationKind, fingerprint: u64) -> Self {
+        let (longest_stability_streak, streak_start_generation) = stats::longest_stability_streak(&game.generation_data);
+        let populations = game.population_series();
+        SimulationResult {
+            final_stability_score: current_stability_score(&populations, game.stability_window),
+            stats: PopulationStats::from_generations(&game.generation_data),
+            longest_stability_streak,
+            streak_start_generation,
+            ages: game.track_ages().then(|| AgeStats::from_ages(&game.cell_ages)),
+            density_series: game.track_density().then(|| game.density_series()),
+            oldest_cell_age: game.oldest_cell_age(),
+            age_histogram: game.birth_tracking().then(|| game.age_histogram()),
+            entropy_series: game.track_entropy.then_some(game.entropy_series),
+            quadrant_series: game.track_quadrants.then_some(game.quadrant_series),
+            component_count_series: game.track_components.then_some(game.component_series),
+            heatmap: game.track_heatmap.then(|| heatmap::heatmap_json(&game.heatmap)),
+            snapshots: (!game.snapshot_at.is_empty()).then_some(game.snapshots),
+            tagged_populations: (!game.tagged_cells.is_empty()).then_some(game.tagged_populations),
+            generations: game.generation_data,
+            generation_deltas: None,
+            stabilized_at: stabilization_kind.generation(),
+            stabilization_kind,
+            topology: game.topology,
+            stability_window: game.stability_window,
+            fingerprint,
+            offset: None,
+            deltas: None,
+            rolling_avg_growth: None,
+            autocorrelation: None,
+            top_cells: None,
+            neighbor_distribution_final: None,
+            dominant_period_estimate: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GameOfLife {
+    pub live_cells: HashSet<Point>,
+    neighborhood: Neighborhood,
+    neighbor_offsets: Vec<(i64, i64)>,
+    pub generation_data: BTreeMap<usize, usize>,
+    pub cell_ages: HashMap<Point, usize>,
+    pub entropy_series: BTreeMap<usize, f64>,
+    pub quadrant_series: BTreeMap<usize, [usize; 4]>,
+    pub component_series: BTreeMap<usize, usize>,
+    heatmap: HashMap<Point, usize>,
+    pub birth_generation: HashMap<Point, usize>,
+    pub snapshots: BTreeMap<usize, Vec<[i64; 2]>>,
+    snapshot_at: HashSet<usize>,
+    pub tagged_cells: HashMap<String, HashSet<Point>>,
+    pub tagged_populations: BTreeMap<String, BTreeMap<usize, usize>>,
+    bbox_areas: BTreeMap<usize, u64>,
+    topology: Topology,
+    stability_window: usize,
+    max_population: Option<usize>,
+    max_cells: Option<usize>,
+    variance_stop: Option<(f64, usize)>,
+    rule: Rule,
+    spatial_index: bool,
+    track_ages: bool,
+    track_entropy: bool,
+    track_density: bool,
+    density_interval: usize,
+    track_quadrants: bool,
+    track_components: bool,
+    track_heatmap: bool,
+    birth_tracking: bool,
+    generation: usize,
+    quiet: bool,
+    progress_bar: bool,
+    generation_callback: Option<callback::GenerationCallback>,
+    display_limit: (usize, usize),
+}
+
+// Batches, sweeps and `simulate_async` move games across threads. Anything
+// stored in the game, such as the generation callback, has to keep this.
+static_assertions::assert_impl_all!(GameOfLife: Send, Sync);
+
+pub const DEFAULT_STABILITY_WINDOW: usize = 50;
+
+impl Default for GameOfLife {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameOfLife {
+    pub fn new() -> Self {
+        GameOfLife {
+            live_cells: HashSet::new(),
+            neighborhood: Neighborhood::Moore,
+            neighbor_offsets: Neighborhood::Moore.offsets(),
+            generation_data: BTreeMap::new(),
+            cell_ages: HashMap::new(),
+            entropy_series: BTreeMap::new(),
+            quadrant_series: BTreeMap::new(),
+            component_series: BTreeMap::new(),
+            heatmap: HashMap::new(),
+            birth_generation: HashMap::new(),
+            snapshots: BTreeMap::new(),
+            snapshot_at: HashSet::new(),
+            tagged_cells: HashMap::new(),
+            tagged_populations: BTreeMap::new(),
+            bbox_areas: BTreeMap::new(),
+            topology: Topology::Infinite,
+            stability_window: DEFAULT_STABILITY_WINDOW,
+            max_population: None,
+            max_cells: None,
+            variance_stop: None,
+            rule: Rule::conway(),
+            spatial_index: false,
+            track_ages: false,
+            track_entropy: false,
+            track_density: false,
+            density_interval: 1,
+            track_quadrants: false,
+            track_components: false,
+            track_heatmap: false,
+            birth_tracking: false,
+            generation: 0,
+            quiet: false,
+            progress_bar: false,
+            generation_callback: None,
+            display_limit: display::DEFAULT_DISPLAY_LIMIT,
+        }
+    }
+
+    pub fn with_rule(rule: Rule) -> Self {
+        GameOfLife {
+            rule,
+            ..Self::new()
+        }
+    }
+
+    pub fn with_topology(topology: Topology) -> Self {
+        GameOfLife {
+            topology,
+            ..Self::new()
+        }
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    pub fn set_rule(&mut self, rule: Rule) {
+        self.rule = rule;
+    }
+
+    // The last generation stepped to, or the one restored from a checkpoint.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // The populations in `generation_data` in generation order, so index `i`
+    // is generation `i` for a run started from generation 0. A run resumed
+    // from a checkpoint starts at the checkpoint's generation instead.
+    pub fn population_series(&self) -> Vec<usize> {
+        self.generation_data.values().copied().collect()
+    }
+
+    pub fn stability_window(&self) -> usize {
+        self.stability_window
+    }
+
+    // A zero-length window would call every generation stable, so it is raised to 1.
+    pub fn set_stability_window(&mut self, w: usize) {
+        self.stability_window = w.max(1);
+    }
+
+    pub fn max_population(&self) -> Option<usize> {
+        self.max_population
+    }
+
+    // Runs stop as soon as the population grows past this, before patterns
+    // that grow without bound exhaust memory.
+    pub fn set_max_population(&mut self, max_population: Option<usize>) {
+        self.max_population = max_population;
+    }
+
+    pub fn max_cells(&self) -> Option<usize> {
+        self.max_cells
+    }
+
+    // A hard memory guard: like `max_population`, but the run ends with
+    // `MemoryLimitExceeded`, which the binary reports as an error.
+    pub fn set_max_cells(&mut self, max_cells: Option<usize>) {
+        self.max_cells = max_cells;
+    }
+
+    pub fn stop_variance(&self) -> Option<(f64, usize)> {
+        self.variance_stop
+    }
+
+    // Runs end with `StabilizationKind::LowVariance` once the variance of the
+    // last `window` populations drops below `threshold`, for patterns that
+    // settle near a population without holding it exactly.
+    pub fn set_stop_variance(&mut self, stop: Option<(f64, usize)>) {
+        self.variance_stop = stop;
+    }
+
+    // Age, birth, entropy, density, quadrant, component and heatmap tracking,
+    // snapshots and the
+    // generation callback look at every generation's cells.
+    pub(crate) fn tracks_every_generation(&self) -> bool {
+        self.track_ages
+            || self.birth_tracking
+            || self.track_entropy
+            || self.track_density
+            || self.track_quadrants
+            || self.track_components
+            || self.track_heatmap
+            || !self.snapshot_at.is_empty()
+            || !self.tagged_cells.is_empty()
+            || self.generation_callback.is_some()
+    }
+
+    // `first` is the generation a run starts from, which is always measured.
+    fn record_tracking(&mut self, generation: usize, first: bool) {
+        self.record_entropy(generation);
+        self.record_area(generation, first);
+        self.record_quadrants(generation);
+        self.record_components(generation);
+        self.record_heatmap();
+        self.record_births(generation);
+        self.record_snapshot(generation);
+        self.record_tags(generation);
+    }
+
+    // Turns off the progress bar and the stop messages, e.g. for runs on
+    // other threads.
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    // Draws a progress bar on stderr during runs when stderr is a terminal.
+    // Off by default, so a program using the library keeps its terminal; the
+    // binary turns it on for `run`. Without it, or with stderr redirected,
+    // progress is a debug line every 1000 generations.
+    pub fn set_progress_bar(&mut self, enabled: bool) {
+        self.progress_bar = enabled;
+    }
+
+    pub(crate) fn log(&self, level: Level, message: std::fmt::Arguments) {
+        if !self.quiet {
+            log_at(level, message);
+        }
+    }
+
+    pub fn initialize_pattern(&mut self, pattern: &[&str], start_x: i64, start_y: i64) -> Result<(), PowercelaError> {
+        for (y, row) in pattern.iter().enumerate() {
+            if let Some(ch) = row.chars().find(|&ch| ch != '0' && ch != '1') {
+                return Err(PowercelaError::InvalidPattern(format!("unexpected character {:?} in row {}", ch, y)));
+            }
+        }
+        self.insert_rows(pattern, start_x, start_y);
+        Ok(())
+    }
+
+    // Coordinates wrap at the i64 limits rather than overflowing.
+    pub(crate) fn insert_rows(&mut self, pattern: &[&str], start_x: i64, start_y: i64) {
+        for (y, row) in pattern.iter().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                if ch == '1' {
+                    let cell = Point::new(start_x.wrapping_add(x as i64), start_y.wrapping_add(y as i64));
+                    self.live_cells.insert(self.topology.wrap(cell));
+                }
+            }
+        }
+    }
+
+    // `iterations` is the generation to stop at. With `resume_from`, stepping
+    // picks up after that generation and adds to the existing `generation_data`.
+    #[tracing::instrument(level = "info", skip(self), fields(population = self.live_cells.len()))]
+    pub fn simulate(&mut self, iterations: usize, resume_from: Option<usize>) -> StabilizationKind {
+        self.run(iterations, resume_from.unwrap_or(0), Self::next_generation)
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(population = self.live_cells.len()))]
+    pub fn simulate_parallel(&mut self, iterations: usize) -> StabilizationKind {
+        self.run(iterations, 0, Self::next_generation_parallel)
+    }
+
+    fn next_generation(&self) -> HashSet<Point> {
+        if self.spatial_index
+            && self.topology.is_infinite()
+            && self.neighborhood.is_moore()
+            && spatial::fits(&self.live_cells)
+        {
+            return SpatialIndex::build(&self.live_cells).next_generation(&self.rule);
+        }
+
+        let counting = tracing::debug_span!("count_neighbors").entered();
+        let mut neighbor_counts = HashMap::with_capacity(self.live_cells.len() * self.neighbor_offsets.len());
+
+        for &cell in &self.live_cells {
+            for &(dx, dy) in &self.neighbor_offsets {
+                let neighbor = self.topology.wrap(Point::new(cell.x.wrapping_add(dx), cell.y.wrapping_add(dy)));
+                *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        counting.exit();
+
+        let _applying = tracing::debug_span!("apply_rule").entered();
+        let mut new_live_cells = self.isolated_survivors(&neighbor_counts);
+        for (cell, count) in neighbor_counts {
+            if self.rule.next_state(self.live_cells.contains(&cell), count) {
+                new_live_cells.insert(cell);
+            }
+        }
+        new_live_cells
+    }
+
+    // Live cells with no live neighbors never show up in the neighbor counts,
+    // so rules with S0 have to keep them explicitly.
+    fn isolated_survivors(&self, neighbor_counts: &HashMap<Point, u8>) -> HashSet<Point> {
+        if !self.rule.survival.contains(&0) {
+            return HashSet::new();
+        }
+        self.live_cells
+            .iter()
+            .filter(|cell| !neighbor_counts.contains_key(cell))
+            .copied()
+            .collect()
+    }
+
+    fn next_generation_parallel(&self) -> HashSet<Point> {
+        let counting = tracing::debug_span!("count_neighbors").entered();
+        let neighbor_counts = self
+            .live_cells
+            .par_iter()
+            .fold(HashMap::new, |mut counts: HashMap<Point, u8>, &cell| {
+                for &(dx, dy) in &self.neighbor_offsets {
+                    let neighbor = self.topology.wrap(Point::new(cell.x.wrapping_add(dx), cell.y.wrapping_add(dy)));
+                    *counts.entry(neighbor).or_insert(0) += 1;
+                }
+                counts
+            })
+            .reduce(HashMap::new, |mut merged, mut counts| {
+                if merged.len() < counts.len() {
+                    std::mem::swap(&mut merged, &mut counts);
+                }
+                for (cell, count) in counts {
+                    *merged.entry(cell).or_insert(0) += count;
+                }
+                merged
+            });
+        counting.exit();
+
+        let _applying = tracing::debug_span!("apply_rule").entered();
+        let mut new_live_cells: HashSet<Point> = neighbor_counts
+            .par_iter()
+            .filter(|&(cell, &count)| self.rule.next_state(self.live_cells.contains(cell), count))
+            .map(|(&cell, _)| cell)
+            .collect();
+        new_live_cells.extend(self.isolated_survivors(&neighbor_counts));
+        new_live_cells
+    }
+
+    // Like `simulate`, but calls `observer` with every generation's live cells,
+    // e.g. to snapshot a particular generation.
+    pub fn simulate_observed<E>(
+        &mut self,
+        iterations: usize,
+        observer: impl FnMut(usize, &HashSet<Point>) -> Result<(), E>,
+    ) -> Result<StabilizationKind, E> {
+        self.run_observed(iterations, 0, Self::next_generation, observer)
+    }
+
+    fn run(&mut self, iterations: usize, start: usize, next_generation: fn(&Self) -> HashSet<Point>) -> StabilizationKind {
+        match self.run_observed(iterations, start, next_generation, |_, _| Ok::<(), Infallible>(())) {
+            Ok(kind) => kind,
+            Err(never) => match never {},
+        }
+    }
+
+    fn run_observed<E>(
+        &mut self,
+        iterations: usize,
+        start: usize,
+        next_generation: fn(&Self) -> HashSet<Point>,
+        mut observer: impl FnMut(usize, &HashSet<Point>) -> Result<(), E>,
+    ) -> Result<StabilizationKind, E> {
+        let mut generation_data = std::mem::take(&mut self.generation_data);
+        let result = self.run_with(iterations, start, next_generation, |generation, cells| {
+            generation_data.insert(generation, cells.len());
+            observer(generation, cells)
+        });
+        self.generation_data = generation_data;
+        result
+    }
+
+    // Replaces the live cells with `generation`'s, keeping ages and the
+    // per-generation tracking up to date.
+    pub(crate) fn step_generation(&mut self, generation: usize, next_generation: fn(&Self) -> HashSet<Point>) {
+        let live_cells = next_generation(self);
+        self.advance_to(generation, live_cells);
+    }
+
+    // The rest of `step_generation` once the next generation's cells are known.
+    pub(crate) fn advance_to(&mut self, generation: usize, live_cells: HashSet<Point>) {
+        self.live_cells = live_cells;
+        self.advance_ages();
+        self.generation = generation;
+        self.record_tracking(generation, false);
+        self.notify_generation(generation);
+    }
+
+    // Drives the simulation, handing every generation's live cells to `record`.
+    // Stability is tracked from the populations seen so far, so nothing has to
+    // be kept in `generation_data`. A run resumed at `start` begins a fresh
+    // stability window there.
+    pub(crate) fn run_with<E>(
+        &mut self,
+        iterations: usize,
+        start: usize,
+        next_generation: fn(&Self) -> HashSet<Point>,
+        mut record: impl FnMut(usize, &HashSet<Point>) -> Result<(), E>,
+    ) -> Result<StabilizationKind, E> {
+        self.sync_ages();
+        self.generation = start;
+        self.record_tracking(start, true);
+        record(start, &self.live_cells)?;
+        let mut stability = StabilityTracker::new(self.stability_window, self.live_cells.len())
+            .with_variance_stop(self.variance_stop, self.live_cells.len());
+        let mut progress = match (self.quiet, self.progress_bar) {
+            (true, _) => ProgressReporter::hidden(),
+            (false, true) => ProgressReporter::new(iterations as u64),
+            (false, false) => ProgressReporter::logging(),
+        };
+
+        for current_gen in start + 1..=iterations {
+            self.step_generation(current_gen, next_generation);
+
+            let population = self.live_cells.len();
+            record(current_gen, &self.live_cells)?;
+            progress.update_with_stability(current_gen, population, stability.score_with(population));
+
+            if population == 0 {
+                progress.finish();
+                self.log(Level::INFO, format_args!("Population went extinct at generation {}", current_gen));
+                return Ok(StabilizationKind::Extinction(current_gen));
+            }
+            if self.max_cells.is_some_and(|max| population > max) {
+                progress.finish();
+                self.log(Level::WARN, format_args!("Live cells exceeded the limit with {} at generation {}", population, current_gen));
+                return Ok(StabilizationKind::MemoryLimitExceeded { generation: current_gen, count: population });
+            }
+            if self.max_population.is_some_and(|max| population > max) {
+                progress.finish();
+                self.log(Level::WARN, format_args!("Population exploded to {} at generation {}", population, current_gen));
+                return Ok(StabilizationKind::Explosion { generation: current_gen, population });
+            }
+
+            let checking = tracing::debug_span!("check_stability").entered();
+            if let Some(kind) = stability.observe(current_gen, population) {
+                progress.finish();
+                self.log(Level::INFO, format_args!("{}", stability.describe(kind, population)));
+                return Ok(kind);
+            }
+            checking.exit();
+        }
+
+        progress.finish();
+        self.log(Level::INFO, format_args!("Simulation completed all {} iterations without stabilizing", iterations));
+        Ok(StabilizationKind::None)
+    }
+}
+
+// The library only emits `tracing` events; the binary installs the subscriber.
+pub(crate) fn log_at(level: Level, message: std::fmt::Arguments) {
+    match level {
+        Level::ERROR => tracing::error!("{}", message),
+        Level::WARN => tracing::warn!("{}", message),
+        Level::INFO => tracing::info!("{}", message),
+        Level::DEBUG => tracing::debug!("{}", message),
+        Level::TRACE => tracing::trace!("{}", message),
+    }
+}
+
+pub fn split_pattern(pattern: &str, split_amount: usize) -> Result<Vec<String>, PowercelaError> {
+    if split_amount == 0 {
+        return Err(PowercelaError::InvalidPattern("split amount must be at least 1".to_string()));
+    }
+    Ok(pattern
+        .chars()
+        .collect::<Vec<char>>()
+        .chunks(split_amount)
+        .map(|chunk| chunk.iter().collect())
+        .collect())
+}
@@ -0,0 +1,35 @@
+use crate::{split_pattern, GameOfLife, PowercelaError, Rule};
+
+// What one request to the HTTP or gRPC server may ask for. Requests for more
+// iterations are rejected, and runs stop with `MemoryLimitExceeded` past
+// `MAX_CELLS`, so one client cannot tie up the server indefinitely.
+pub const MAX_ITERATIONS: usize = 100_000;
+pub const MAX_CELLS: usize = 1_000_000;
+
+// A quiet game within the limits above. `pattern` is a built-in pattern name,
+// or a flat 0/1 string when `split_amount` is given, centered on the origin as
+// on the command line. `rule` defaults to B3/S23.
+pub(crate) fn build_game(
+    pattern: &str,
+    split_amount: Option<usize>,
+    rule: Option<&str>,
+    iterations: usize,
+) -> Result<GameOfLife, PowercelaError> {
+    if iterations > MAX_ITERATIONS {
+        return Err(PowercelaError::ParseError(format!("iterations must be at most {}", MAX_ITERATIONS)));
+    }
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.set_max_cells(Some(MAX_CELLS));
+    if let Some(rule) = rule {
+        game.set_rule(rule.parse::<Rule>()?);
+    }
+    match split_amount {
+        Some(split_amount) => {
+            let rows = split_pattern(pattern, split_amount)?;
+            game.initialize_pattern_at_origin(&rows.iter().map(String::as_str).collect::<Vec<_>>())?;
+        }
+        None => game.load_named(pattern, 0, 0)?,
+    }
+    Ok(game)
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use std::io::{self, Stdout, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::{cursor, execute, queue, terminal};
+
+use crate::{BoundingBox, Point};
+
+// Full-screen terminal preview of the live cells. The terminal is switched to
+// the alternate screen while this is alive and restored on drop.
+pub struct LiveView {
+    stdout: Stdout,
+    interval: Duration,
+    last_draw: Option<Instant>,
+}
+
+impl LiveView {
+    pub fn new(interval: Duration) -> io::Result<Self> {
+        let mut stdout = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(LiveView { stdout, interval, last_draw: None })
+    }
+
+    // Redraws at most once per interval, centered on the bounding box.
+    // Returns false once the user has pressed `q` (or Ctrl-C).
+    pub fn update(&mut self, generation: usize, cells: &HashSet<Point>) -> io::Result<bool> {
+        self.update_with(generation, Some(cells), cells.len())
+    }
+
+    // Like `update` when only the population is known: the grid is left
+    // blank.
+    pub fn update_population(&mut self, generation: usize, population: usize) -> io::Result<bool> {
+        self.update_with(generation, None, population)
+    }
+
+    fn update_with(&mut self, generation: usize, cells: Option<&HashSet<Point>>, population: usize) -> io::Result<bool> {
+        while event::poll(Duration::ZERO)? {
+            if let Event::Key(key) = event::read()? {
+                let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                if key.kind == KeyEventKind::Press && (key.code == KeyCode::Char('q') || ctrl_c) {
+                    return Ok(false);
+                }
+            }
+        }
+
+        if self.last_draw.is_some_and(|last| last.elapsed() < self.interval) {
+            return Ok(true);
+        }
+        self.last_draw = Some(Instant::now());
+        self.draw(generation, cells, population)?;
+        Ok(true)
+    }
+
+    fn draw(&mut self, generation: usize, cells: Option<&HashSet<Point>>, population: usize) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let grid_rows = rows.saturating_sub(1) as i64;
+        let center = cells.and_then(BoundingBox::from_cells).map_or(Point::new(0, 0), |bbox| bbox.center());
+        let left = center.x.wrapping_sub(columns as i64 / 2);
+        let top = center.y.wrapping_sub(grid_rows / 2);
+        let alive = |cell: &Point| cells.is_some_and(|cells| cells.contains(cell));
+
+        queue!(self.stdout, cursor::MoveTo(0, 0))?;
+        for row in 0..grid_rows {
+            let y = top.wrapping_add(row);
+            let line: String = (0..columns as i64)
+                .map(|column| if alive(&Point::new(left.wrapping_add(column), y)) { '█' } else { ' ' })
+                .collect();
+            queue!(self.stdout, cursor::MoveTo(0, row as u16), Print(line))?;
+        }
+        let status = format!("Generation {}  Population {}  (q to stop)", generation, population);
+        let status: String = status.chars().take(columns as usize).collect();
+        queue!(
+            self.stdout,
+            cursor::MoveTo(0, grid_rows as u16),
+            terminal::Clear(terminal::ClearType::CurrentLine),
+            Print(status)
+        )?;
+        self.stdout.flush()
+    }
+}
+
+impl Drop for LiveView {
+    fn drop(&mut self) {
+        let _ = execute!(self.stdout, cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -1,159 +1,1229 @@
-use std::collections::{BTreeMap, HashSet};
+use std::collections::HashMap;
 use std::env;
-use std::time::Instant;
-use std::fs;
-use serde::{Serialize};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use powercela::formats::life105::export_life105;
+use powercela::output::{
+    read_result, write_batch, write_batch_to, write_cells_csv, write_result, write_result_to, Compression, OutputFormat,
+};
+use powercela::render::{render_png, render_rows_png, render_svg_with, write_heatmap_png, SvgStyle, CELL_SIZES};
+use powercela::stats::DEFAULT_DELTA_WINDOW;
+#[cfg(feature = "live-view")]
+use powercela::live_view::LiveView;
+#[cfg(feature = "telemetry")]
+use powercela::telemetry::Telemetry;
+use powercela::config::{DEFAULT_CONFIG_FILE, EXAMPLE_CONFIG};
+use powercela::{
+    batch_simulate, neighbor_count_distribution, rule_sweep, simulate_1d, split_pattern, tournament_by, Config, GameOfLife, GenerationsGame, GenerationsRule, Neighborhood, PatternCategory, PatternId, PatternLibrary, PatternQuery,
+    Point, PowercelaError, RandomSpec, Rule, RuleParseError, SimulationResult, StabilizationKind, TopCell, Topology, WinCriterion,
+};
+use tracing::{debug, error, info, warn, Level};
+use tracing_subscriber::fmt::writer::MakeWriterExt;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-struct Point {
-    x: i32,
-    y: i32,
+#[derive(Parser)]
+#[command(name = "powercela", version, about = "Life-like cellular automaton simulator")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
 }
 
-impl Point {
-    fn new(x: i32, y: i32) -> Self {
-        Point { x, y }
+#[derive(Subcommand)]
+enum Command {
+    /// Simulate a pattern and write its population per generation
+    Run(Box<RunArgs>),
+    /// Play back a saved JSON result one generation per frame
+    Replay(ReplayArgs),
+    /// Print a pattern's size and population without simulating
+    Info(PatternArgs),
+    /// Show the built-in pattern library, or the patterns matching some criteria
+    ListPatterns(ListPatternsArgs),
+    /// Simulate two patterns side by side and report which one wins
+    Tournament(TournamentArgs),
+    /// Manage the powercela.toml config file
+    Config(ConfigArgs),
+}
+
+#[derive(Args)]
+struct PatternArgs {
+    /// Built-in pattern name, or a flat 0/1 string when --split is given
+    #[arg(long)]
+    pattern: String,
+    /// Row length used to split a flat pattern string
+    #[arg(long)]
+    split: Option<usize>,
+}
+
+#[derive(Args)]
+struct ListPatternsArgs {
+    /// still-life, oscillator, spaceship, gun or other
+    #[arg(long)]
+    category: Option<PatternCategory>,
+    /// Generations until the pattern repeats (1 for still lifes)
+    #[arg(long)]
+    period: Option<usize>,
+    /// Only patterns with at least this many live cells
+    #[arg(long, value_name = "N")]
+    min_cells: Option<usize>,
+    /// Only patterns with at most this many live cells
+    #[arg(long, value_name = "N")]
+    max_cells: Option<usize>,
+    /// Only patterns whose name contains this, ignoring case
+    #[arg(long, value_name = "TEXT")]
+    name: Option<String>,
+}
+
+#[derive(Args)]
+struct TournamentArgs {
+    /// Built-in pattern name of the first contestant
+    #[arg(long)]
+    pattern_a: String,
+    /// Built-in pattern name of the second contestant
+    #[arg(long)]
+    pattern_b: String,
+    /// Generations each pattern gets
+    #[arg(long, default_value_t = 1000)]
+    iterations: usize,
+    /// stabilizes-first, larger-final-population or lower-final-population
+    #[arg(long, default_value_t = WinCriterion::StabilizesFirst)]
+    criterion: WinCriterion,
+}
+
+impl ListPatternsArgs {
+    fn query(&self) -> PatternQuery {
+        let mut query = PatternQuery::new();
+        if let Some(category) = self.category {
+            query = query.category(category);
+        }
+        if let Some(period) = self.period {
+            query = query.period(period);
+        }
+        if let Some(min_cells) = self.min_cells {
+            query = query.min_cells(min_cells);
+        }
+        if let Some(max_cells) = self.max_cells {
+            query = query.max_cells(max_cells);
+        }
+        if let Some(name) = &self.name {
+            query = query.name_contains(name);
+        }
+        query
     }
 }
 
-#[derive(Serialize)]
-struct SimulationResult {
-    generations: BTreeMap<usize, usize>,
-    #[serde(rename = "stabilizedAt")]
-    stabilized_at: Option<usize>,
+// Every value left unset here falls back to the config file, then to the defaults.
+#[derive(Args)]
+struct RunArgs {
+    /// Config file [default: powercela.toml in the working directory, if present]
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Number of generations to simulate
+    #[arg(long)]
+    iterations: Option<usize>,
+    /// Built-in pattern name, or a flat 0/1 string when --split is given
+    #[arg(long)]
+    pattern: Option<String>,
+    /// Row length used to split a flat pattern string
+    #[arg(long)]
+    split: Option<usize>,
+    /// Start from a seeded random soup instead of a pattern: width=<n>,height=<n>,density=<0..1>,seed=<n>
+    #[arg(long, value_name = "SPEC", conflicts_with = "pattern")]
+    random: Option<RandomSpec>,
+    /// Start from a PNG or BMP image: pixels darker than --threshold become live cells
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["pattern", "random", "resume", "batch"])]
+    image_pattern: Option<PathBuf>,
+    /// Luminance (0-255) below which an --image-pattern pixel is live [default: 128]
+    #[arg(long)]
+    threshold: Option<u8>,
+    /// Life-like rule in B/S notation, a Generations rule such as B2/S345/C4 (or 2/345/4, birth/survival/states), or day-and-night [default: B3/S23]
+    #[arg(long)]
+    rule: Option<RuleArg>,
+    /// moore or vonneumann, optionally with a radius such as moore:2 [default: moore]
+    #[arg(long, value_name = "NAME[:RADIUS]")]
+    neighborhood: Option<Neighborhood>,
+    /// Run on a wrap-around grid, e.g. 64x64
+    #[arg(long, value_name = "WIDTHxHEIGHT")]
+    torus: Option<Topology>,
+    /// Generations of constant population that count as stable [default: 50]
+    #[arg(long)]
+    stability_window: Option<usize>,
+    /// Stop once the population grows past this many cells
+    #[arg(long, value_name = "N")]
+    max_population: Option<usize>,
+    /// Fail once the live cells grow past this many, e.g. 10_000_000
+    #[arg(long, value_name = "N", value_parser = parse_cell_count)]
+    max_cells: Option<usize>,
+    /// Stop once the variance of the last --stop-variance-window populations drops below V
+    #[arg(long, value_name = "V")]
+    stop_variance_threshold: Option<f64>,
+    /// Populations in the variance of --stop-variance-threshold [default: 100]
+    #[arg(long, value_name = "W")]
+    stop_variance_window: Option<usize>,
+    /// Result file path [default: result/<pattern>.<format>]
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Result file format: json, csv or parquet [default: json]
+    #[arg(long, alias = "output-format")]
+    format: Option<OutputFormat>,
+    /// Gzip the result file
+    #[arg(long)]
+    compress: bool,
+    /// Spread neighbor counting across all CPU cores
+    #[arg(long)]
+    parallel: bool,
+    /// [default: naive]
+    #[arg(long, value_enum)]
+    algorithm: Option<Algorithm>,
+    /// Move the starting pattern so its bounding box starts at, or is centered on, the origin
+    #[arg(long, value_enum)]
+    normalize: Option<Normalization>,
+    /// Merge a second pattern into the first: union, intersect, subtract or xor
+    #[arg(long, num_args = 2, value_names = ["OP", "PATTERN"])]
+    combine: Option<Vec<String>>,
+    /// Write one JSON line per generation instead of a single result file
+    #[arg(long)]
+    streaming: bool,
+    /// Record how many generations each cell has been alive
+    #[arg(long)]
+    track_ages: bool,
+    /// Record the Shannon entropy of the live cells over 8x8 tiles every generation
+    #[arg(long)]
+    track_entropy: bool,
+    /// Record population divided by bounding box area every generation
+    #[arg(long)]
+    track_density: bool,
+    /// Measure the bounding box for --track-density every N generations and interpolate in between [default: 1]
+    #[arg(long, value_name = "N")]
+    density_interval: Option<usize>,
+    /// Record the live cells in each quadrant around the origin every generation
+    #[arg(long)]
+    track_quadrants: bool,
+    /// Record the number of separate Moore-connected groups of live cells every generation
+    #[arg(long)]
+    track_components: bool,
+    /// Count the generations each cell is alive, add them to the result and draw result/<pattern>.heatmap.png
+    #[arg(long)]
+    track_heatmap: bool,
+    /// Add the N cells alive in the most generations to the result
+    #[arg(long, value_name = "N")]
+    top_cells: Option<usize>,
+    /// Add how many live cells of the last generation have 0 to 8 live neighbors to the result
+    #[arg(long)]
+    neighbor_distribution: bool,
+    /// Record the generation each cell was born in and report the oldest cell and an age histogram
+    #[arg(long)]
+    track_birth_gen: bool,
+    /// Include the live cells at these generations in the result, e.g. 0,100,500
+    #[arg(long, value_name = "GENERATIONS", value_delimiter = ',')]
+    snapshot: Vec<usize>,
+    /// Add each generation's population change and its rolling average to the result
+    #[arg(long)]
+    include_deltas: bool,
+    /// Generations in the rolling average of --include-deltas [default: 10]
+    #[arg(long, value_name = "N")]
+    delta_window: Option<usize>,
+    /// Add the population's autocorrelation at lags 0 to N to the result, e.g. max-lag=64
+    #[arg(long, value_name = "SPEC", value_parser = parse_autocorrelation)]
+    autocorrelation: Option<usize>,
+    /// Add the period of the strongest frequency in the population's spectrum to the result
+    #[arg(long)]
+    spectrum: bool,
+    /// Save result/<pattern>.checkpoint.json every N generations
+    #[arg(long, value_name = "N")]
+    checkpoint_every: Option<usize>,
+    /// Simulate every flat 0/1 pattern in FILE (one per line, needs --split) and write one JSON array
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["pattern", "random", "combine", "resume", "checkpoint_every", "streaming", "export_svg", "export_png", "live_view", "export_format"]
+    )]
+    batch: Option<PathBuf>,
+    /// Simulate the pattern once per rule, e.g. B3/S23,B36/S23, and write one JSON array
+    #[arg(
+        long,
+        value_name = "RULES",
+        value_parser = parse_rule_list,
+        conflicts_with_all = ["rule", "batch", "resume", "checkpoint_every", "streaming", "export_svg", "export_png", "live_view", "cells_csv", "top_cells", "neighbor_distribution", "export_format"]
+    )]
+    sweep_rules: Option<RuleList>,
+    /// Continue a run from a checkpoint file; --iterations is the generation to stop at
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["pattern", "random", "combine"])]
+    resume: Option<PathBuf>,
+    /// Render a generation as SVG: generation=<n>,cell_size=<px>[,fill=,background=,stroke=,stroke_width=]
+    #[arg(long, value_name = "SPEC", value_parser = parse_svg_export)]
+    export_svg: Vec<FrameExport>,
+    /// Render a generation as PNG: generation=<n>[,cell_size=<px>]
+    #[arg(long, value_name = "SPEC", value_parser = parse_png_export)]
+    export_png: Vec<FrameExport>,
+    /// Write every live cell at some generations to result/<pattern>.cells.csv: generations=<n>,<n>,...
+    #[arg(long, value_name = "SPEC", value_parser = parse_cells_csv)]
+    cells_csv: Option<CellsCsv>,
+    /// Write the last generation's live cells to result/<pattern>.lif as Life 1.05
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    export_format: Option<PatternFormat>,
+    /// Draw the grid in the terminal while simulating (live-view feature)
+    #[arg(long)]
+    live_view: bool,
+    /// Serve the HTTP and WebSocket API instead of running (server feature)
+    #[arg(long)]
+    server: bool,
+    /// Serve the Simulator gRPC service instead of running (grpc feature)
+    #[arg(long, conflicts_with = "server")]
+    grpc: bool,
+    /// Port for --server or --grpc [default: 8080 with --server, 50051 with --grpc]
+    #[arg(long)]
+    port: Option<u16>,
+    /// Export tracing spans to an OpenTelemetry collector, e.g. otlp://localhost:4317 (telemetry feature)
+    #[arg(long, value_name = "URL")]
+    telemetry: Option<String>,
+    /// Milliseconds between live view redraws
+    #[arg(long, default_value_t = 100)]
+    view_interval: u64,
+    /// 1d runs an elementary cellular automaton and draws its space-time diagram as a PNG [default: 2d]
+    #[arg(long, value_enum)]
+    mode: Option<Mode>,
+    /// Wolfram code of the elementary rule for --mode 1d, 0-255
+    #[arg(long, value_name = "CODE")]
+    rule_code: Option<u8>,
+    /// Cells in the --mode 1d row [default: the initial row's length, or 128]
+    #[arg(long)]
+    width: Option<usize>,
+    /// Starting --mode 1d row of 0s and 1s, centered in the width [default: one live cell in the middle]
+    #[arg(long, value_name = "BITS")]
+    initial_row: Option<String>,
 }
 
-struct GameOfLife {
-    live_cells: HashSet<Point>,
-    neighbor_offsets: [(i32, i32); 8],
-    generation_data: BTreeMap<usize, usize>,
+impl RunArgs {
+    fn overrides(&self) -> Config {
+        Config {
+            iterations: self.iterations,
+            pattern: self.pattern.clone(),
+            split_amount: self.split,
+            random: self.random,
+            stability_window: self.stability_window,
+            max_population: self.max_population,
+            max_cells: self.max_cells,
+            stop_variance_threshold: self.stop_variance_threshold,
+            stop_variance_window: self.stop_variance_window,
+            rule: match &self.rule {
+                Some(RuleArg::Life(rule)) => Some(rule.clone()),
+                _ => None,
+            },
+            neighborhood: self.neighborhood.clone(),
+            topology: self.torus,
+            format: self.format,
+            compress: self.compress.then_some(true),
+            parallel: self.parallel.then_some(true),
+            algorithm: self.algorithm.map(|algorithm| format!("{:?}", algorithm).to_lowercase()),
+            normalize: self.normalize.map(|normalization| format!("{:?}", normalization).to_lowercase()),
+            streaming: self.streaming.then_some(true),
+            track_ages: self.track_ages.then_some(true),
+            track_entropy: self.track_entropy.then_some(true),
+            track_density: self.track_density.then_some(true),
+            density_interval: self.density_interval,
+            track_quadrants: self.track_quadrants.then_some(true),
+            track_components: self.track_components.then_some(true),
+            track_heatmap: self.track_heatmap.then_some(true),
+            top_cells: self.top_cells,
+            neighbor_distribution: self.neighbor_distribution.then_some(true),
+            track_birth_gen: self.track_birth_gen.then_some(true),
+            snapshot: (!self.snapshot.is_empty()).then(|| self.snapshot.clone()),
+            include_deltas: self.include_deltas.then_some(true),
+            delta_window: self.delta_window,
+            autocorrelation: self.autocorrelation,
+            spectrum: self.spectrum.then_some(true),
+            output: self.output.clone(),
+        }
+    }
+}
+
+// A Generations rule has a third part for the number of states.
+#[derive(Clone)]
+enum RuleArg {
+    Life(Rule),
+    Generations(GenerationsRule),
 }
 
-impl GameOfLife {
-    fn new() -> Self {
-        GameOfLife {
-            live_cells: HashSet::new(),
-            neighbor_offsets: [
-                (-1, -1), (-1, 0), (-1, 1),
-                (0, -1),           (0, 1),
-                (1, -1),  (1, 0),  (1, 1),
-            ],
-            generation_data: BTreeMap::new(),
+impl FromStr for RuleArg {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.split('/').count() == 3 {
+            Ok(RuleArg::Generations(s.parse()?))
+        } else {
+            Ok(RuleArg::Life(s.parse()?))
         }
     }
+}
 
-    fn initialize_pattern(&mut self, pattern: &[&str], start_x: i32, start_y: i32) {
-        for (y, row) in pattern.iter().enumerate() {
-            for (x, ch) in row.chars().enumerate() {
-                if ch == '1' {
-                    self.live_cells.insert(Point::new(start_x + x as i32, start_y + y as i32));
-                }
+#[derive(Args)]
+struct ReplayArgs {
+    /// Result file written by `run --format json`, optionally gzipped
+    path: PathBuf,
+    /// Generations shown per second
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    replay_fps: u32,
+    /// Draw the frames in the terminal instead of printing them (live-view feature)
+    #[arg(long)]
+    live_view: bool,
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    /// Write an example powercela.toml with every field at its default
+    #[arg(long, required = true)]
+    init: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum Algorithm {
+    Naive,
+    Hashlife,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum Mode {
+    #[value(name = "2d")]
+    TwoD,
+    #[value(name = "1d")]
+    OneD,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum Normalization {
+    Corner,
+    Center,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum PatternFormat {
+    #[value(name = "life105")]
+    Life105,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let legacy = legacy_arguments(&args);
+    let deprecated = legacy.is_some();
+    let cli = Cli::parse_from(legacy.unwrap_or(args));
+
+    // The exporter has to exist before the subscriber is installed.
+    #[cfg(feature = "telemetry")]
+    let telemetry = match &cli.command {
+        Command::Run(run_args) => run_args.telemetry.as_deref().map(Telemetry::otlp).transpose(),
+        _ => Ok(None),
+    };
+
+    // Warnings and errors go to stderr and everything else to stdout, at the
+    // level set by RUST_LOG, info by default. Colors only on a terminal.
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let output = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr.with_max_level(Level::WARN).or_else(io::stdout))
+        .with_ansi(io::stdout().is_terminal())
+        .without_time()
+        .with_target(false);
+    let subscriber = tracing_subscriber::registry().with(filter).with(output);
+    #[cfg(feature = "telemetry")]
+    let subscriber = subscriber.with(telemetry.as_ref().ok().and_then(Option::as_ref).map(Telemetry::layer));
+    subscriber.init();
+
+    #[cfg(feature = "telemetry")]
+    let telemetry = telemetry.unwrap_or_else(|e| {
+        error!("{}", e);
+        process::exit(1);
+    });
+    if deprecated {
+        warn!("positional arguments are deprecated and will be removed in 1.0; use `run --iterations <n> --pattern <pattern> [--split <n>]`");
+    }
+
+    let outcome = match cli.command {
+        Command::Run(run_args) => run(*run_args),
+        Command::Replay(replay_args) => replay(&replay_args),
+        Command::Info(pattern_args) => info(&pattern_args),
+        Command::ListPatterns(list_args) => {
+            list_patterns(&list_args);
+            Ok(())
+        }
+        Command::Tournament(tournament_args) => run_tournament(&tournament_args),
+        Command::Config(_) => init_config(),
+    };
+    // Flushes the remaining spans; `process::exit` would skip it.
+    #[cfg(feature = "telemetry")]
+    drop(telemetry);
+    if let Err(e) = outcome {
+        error!("{}", e);
+        process::exit(1);
+    }
+}
+
+// The pre-subcommand interface, `<iterations> <split_amount> <pattern> [options]`
+// or `<iterations> --pattern <name> [options]`, rewritten as a `run` invocation.
+fn legacy_arguments(args: &[String]) -> Option<Vec<String>> {
+    const VALUE_FLAGS: [&str; 9] = [
+        "--torus",
+        "--stability-window",
+        "--output-format",
+        "--rule",
+        "--pattern",
+        "--algorithm",
+        "--export-svg",
+        "--export-png",
+        "--view-interval",
+    ];
+    let first = args.get(1)?;
+    if ["run", "replay", "info", "list-patterns", "tournament", "config", "help", "-h", "--help", "-V", "--version"].contains(&first.as_str()) {
+        return None;
+    }
+
+    let mut translated = vec![args[0].clone(), "run".to_string()];
+    let mut positionals = Vec::new();
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        let values = match arg.as_str() {
+            "--combine" => 2,
+            flag if VALUE_FLAGS.contains(&flag) => 1,
+            flag if flag.starts_with("--") => 0,
+            _ => {
+                positionals.push(arg.clone());
+                continue;
             }
+        };
+        translated.push(arg.clone());
+        translated.extend(rest.by_ref().take(values).cloned());
+    }
+
+    let mut positionals = positionals.into_iter();
+    for flag in ["--iterations", "--split", "--pattern"] {
+        match positionals.next() {
+            Some(value) => translated.extend([flag.to_string(), value]),
+            None => break,
         }
     }
+    translated.extend(positionals);
+    Some(translated)
+}
 
-    fn simulate(&mut self, iterations: usize) -> Option<usize> {
-        self.generation_data.insert(0, self.live_cells.len());
-        const STABILITY_WINDOW: usize = 50;
+fn run(args: RunArgs) -> Result<(), PowercelaError> {
+    if args.server {
+        #[cfg(feature = "server")]
+        return powercela::server::serve(args.port.unwrap_or(8080));
+        #[cfg(not(feature = "server"))]
+        return Err(PowercelaError::ParseError("--server needs a build with the server feature".to_string()));
+    }
+    if args.grpc {
+        #[cfg(feature = "grpc")]
+        return powercela::grpc::serve(args.port.unwrap_or(50051));
+        #[cfg(not(feature = "grpc"))]
+        return Err(PowercelaError::ParseError("--grpc needs a build with the grpc feature".to_string()));
+    }
+    if args.port.is_some() {
+        return Err(PowercelaError::ParseError("--port needs --server or --grpc".to_string()));
+    }
+    let file_config = match &args.config {
+        Some(path) => Config::load(path)?,
+        None => Config::discover()?,
+    };
+    let config = file_config.merge(args.overrides());
+    let iterations = config.iterations.ok_or_else(|| {
+        PowercelaError::ParseError("--iterations is required unless the config file sets it".to_string())
+    })?;
+    if args.mode == Some(Mode::OneD) {
+        return run_1d(&args, iterations, config.output);
+    }
+    if args.rule_code.is_some() || args.width.is_some() || args.initial_row.is_some() {
+        return Err(PowercelaError::ParseError("--rule-code, --width and --initial-row need --mode 1d".to_string()));
+    }
+    if args.threshold.is_some() && args.image_pattern.is_none() {
+        return Err(PowercelaError::ParseError("--threshold needs --image-pattern".to_string()));
+    }
+    // A random soup set on the command line wins over a pattern from the config file, and vice versa.
+    let random = if args.pattern.is_some() || args.image_pattern.is_some() { None } else { config.random };
+    let pattern = match (random, config.pattern.clone()) {
+        _ if args.resume.is_some() || args.batch.is_some() || args.image_pattern.is_some() => None,
+        (Some(_), _) => None,
+        (None, Some(pattern)) => Some(PatternArgs { pattern, split: config.split_amount }),
+        (None, None) => {
+            return Err(PowercelaError::ParseError(
+                "--pattern or --random is required unless the config file sets one".to_string(),
+            ))
+        }
+    };
+    let algorithm = match &config.algorithm {
+        Some(name) => Algorithm::from_str(name, true).map_err(PowercelaError::ParseError)?,
+        None => Algorithm::Naive,
+    };
+    let normalization = match &config.normalize {
+        Some(name) => Some(Normalization::from_str(name, true).map_err(PowercelaError::ParseError)?),
+        None => None,
+    };
+    let format = config.format.unwrap_or(OutputFormat::Json);
+    let topology = config.topology.unwrap_or_default();
+    let live_view = args.live_view.then(|| Duration::from_millis(args.view_interval));
+    let exports: Vec<FrameExport> = args.export_svg.into_iter().chain(args.export_png).collect();
+    let compression = if config.compress.unwrap_or(false) {
+        Compression::Gzip(flate2::Compression::default())
+    } else {
+        Compression::None
+    };
+    let generations_rule = match &args.rule {
+        Some(RuleArg::Generations(rule)) => Some(rule.clone()),
+        _ => None,
+    };
+    if generations_rule.is_some()
+        && (config.streaming.unwrap_or(false)
+            || config.parallel.unwrap_or(false)
+            || algorithm == Algorithm::Hashlife
+            || args.checkpoint_every.is_some()
+            || args.resume.is_some()
+            || args.batch.is_some()
+            || !exports.is_empty()
+            || args.cells_csv.is_some()
+            || args.export_format.is_some()
+            || live_view.is_some())
+    {
+        return Err(PowercelaError::ParseError(
+            "Generations rules only support the serial naive stepper without streaming, checkpoints, batches, exports or live view"
+                .to_string(),
+        ));
+    }
+    let checkpointing = args.checkpoint_every.is_some() || args.resume.is_some();
+    if checkpointing
+        && (config.streaming.unwrap_or(false)
+            || config.parallel.unwrap_or(false)
+            || algorithm == Algorithm::Hashlife
+            || !exports.is_empty()
+            || live_view.is_some())
+    {
+        return Err(PowercelaError::ParseError(
+            "--checkpoint-every and --resume only support the serial naive stepper without exports or live view"
+                .to_string(),
+        ));
+    }
+    if args.cells_csv.is_some() && (config.streaming.unwrap_or(false) || args.batch.is_some()) {
+        return Err(PowercelaError::ParseError("--cells-csv cannot be combined with --streaming or --batch".to_string()));
+    }
+    if config.top_cells.is_some() && (config.streaming.unwrap_or(false) || args.batch.is_some()) {
+        return Err(PowercelaError::ParseError("--top-cells cannot be combined with --streaming or --batch".to_string()));
+    }
+    if config.neighbor_distribution.unwrap_or(false) && (config.streaming.unwrap_or(false) || args.batch.is_some()) {
+        return Err(PowercelaError::ParseError(
+            "--neighbor-distribution cannot be combined with --streaming or --batch".to_string(),
+        ));
+    }
+    if args.export_format.is_some() && config.streaming.unwrap_or(false) {
+        return Err(PowercelaError::ParseError("--export-format cannot be combined with --streaming".to_string()));
+    }
+    if args.telemetry.is_some() && cfg!(not(feature = "telemetry")) {
+        return Err(PowercelaError::ParseError("--telemetry needs a build with the telemetry feature".to_string()));
+    }
+    if live_view.is_some() && cfg!(not(feature = "live-view")) {
+        return Err(PowercelaError::ParseError("--live-view needs a build with the live-view feature".to_string()));
+    }
+
+    if let Some(path) = &args.batch {
+        return run_batch(path, &config, iterations, compression);
+    }
 
-        for step in 0..iterations {
-            let mut neighbor_counts = std::collections::HashMap::with_capacity(self.live_cells.len() * 8);
+    info!("Running Conway's Game of Life in Rust");
+    info!("Iterations: {}", iterations);
 
-            for &cell in &self.live_cells {
-                for &(dx, dy) in &self.neighbor_offsets {
-                    let neighbor = Point::new(cell.x + dx, cell.y + dy);
-                    *neighbor_counts.entry(neighbor).or_insert(0) += 1;
-                }
+    let mut game = config.builder().build()?;
+    game.set_progress_bar(true);
+    if let Some(cells_csv) = &args.cells_csv {
+        let mut snapshot_at = game.snapshot_at().clone();
+        snapshot_at.extend(&cells_csv.generations);
+        game.set_snapshot_at(snapshot_at);
+    }
+    let stability_window = game.stability_window();
+    info!("Stability Window: {}", stability_window);
+    match &generations_rule {
+        Some(rule) => info!("Rule: {}", rule),
+        None => info!("Rule: {}", game.rule()),
+    }
+    info!("Neighborhood: {}", game.neighborhood());
+
+    let mut resume_from = None;
+    let pattern_name = match (&pattern, random, &args.resume, &args.image_pattern) {
+        (_, _, _, Some(path)) => {
+            let threshold = args.threshold.unwrap_or(DEFAULT_IMAGE_THRESHOLD);
+            game.load_from_image(path, threshold)?;
+            info!("Image pattern: {} at threshold {}", path.display(), threshold);
+            path.file_stem().map_or_else(|| "image".to_string(), |stem| stem.to_string_lossy().into_owned())
+        }
+        (_, _, Some(path), None) => {
+            let checkpoint = GameOfLife::load_checkpoint(path)?;
+            info!("Resuming from {} at generation {}", path.display(), checkpoint.generation());
+            resume_from = Some(checkpoint.generation());
+            game.live_cells = checkpoint.live_cells;
+            game.generation_data = checkpoint.generation_data;
+            checkpoint_pattern_name(path)
+        }
+        (Some(pattern), _, None, None) => load_pattern(&mut game, pattern)?,
+        (None, Some(spec), None, None) => {
+            info!("Random pattern: {}x{} at density {}, seed {}", spec.width, spec.height, spec.density, spec.seed);
+            game.load_random(&spec)?;
+            spec.to_string()
+        }
+        (None, None, None, None) => unreachable!("pattern source checked above"),
+    };
+
+    if let Some(combine) = args.combine {
+        let (op, pattern_b) = (&combine[0], &combine[1]);
+        let mut other = GameOfLife::new();
+        other.set_topology(topology);
+        if PatternLibrary::get(pattern_b).is_some() {
+            other.load_named(pattern_b, 0, 0)?;
+        } else {
+            let split_amount = pattern.as_ref().and_then(|pattern| pattern.split).ok_or_else(|| {
+                PowercelaError::InvalidPattern("combining a flat pattern requires a split amount".to_string())
+            })?;
+            load_flat_pattern(&mut other, pattern_b, split_amount)?;
+        }
+
+        info!("Combining with {} ({})", pattern_b, op);
+        match op.as_str() {
+            "union" => game.overlay(&other),
+            "intersect" => game.intersect(&other),
+            "subtract" => game.subtract(&other),
+            "xor" => game.symmetric_difference(&other),
+            _ => return Err(PowercelaError::ParseError(format!("unknown combine operation {:?}", op))),
+        };
+    }
+
+    let offset = match normalization {
+        Some(Normalization::Corner) => Some(game.normalize()),
+        Some(Normalization::Center) => Some(game.normalize_centered()),
+        None => None,
+    };
+    if let Some((dx, dy)) = offset {
+        info!("Pattern moved by ({}, {})", dx, dy);
+    }
+    let fingerprint = game.pattern_fingerprint();
+    info!("Pattern fingerprint: {:016x}", fingerprint);
+
+    fs::create_dir_all("result")?;
+
+    if let Some(RuleList(rules)) = args.sweep_rules {
+        info!("Sweeping {} rules for {} iterations", rules.len(), iterations);
+        let start_time = Instant::now();
+        let mut results: Vec<(String, SimulationResult)> = rule_sweep(game, rules, iterations)
+            .into_iter()
+            .map(|(rule, mut result)| {
+                result.offset = offset;
+                (rule.to_string(), result)
+            })
+            .collect();
+        for (_, result) in &mut results {
+            include_requested_series(result, &config);
+        }
+        info!("Sweep completed in {:?}", start_time.elapsed());
+        let output_file = match &config.output {
+            Some(output) => {
+                write_batch_to(output, &results, compression)?;
+                output.clone()
             }
+            None => write_batch(&PathBuf::from(format!("result/{}.sweep", pattern_name)), &results, compression)?,
+        };
+        info!("Sweep results written to {}", output_file.display());
+        return Ok(());
+    }
+
+    if let Some(rule) = generations_rule {
+        let mut generations = GenerationsGame::from_game(&game, rule);
+        let start_time = Instant::now();
+        let stabilization_kind = generations.simulate_generations(iterations);
+        info!("Simulation completed in {:?}", start_time.elapsed());
+        let mut result = SimulationResult::from_generations(generations, stabilization_kind, fingerprint);
+        result.offset = offset;
+        return write_run_result(result, config, &pattern_name, format, compression);
+    }
+
+    if config.streaming.unwrap_or(false) {
+        let output_file = config.output.unwrap_or_else(|| PathBuf::from(format!("result/{}.jsonl", pattern_name)));
+        info!("Simulating, streaming to {}", output_file.display());
+        let start_time = Instant::now();
+        let writer = BufWriter::new(File::create(&output_file)?);
+        let stabilized_at = game.simulate_streaming(writer, iterations)?;
+        info!("Simulation completed in {:?}", start_time.elapsed());
+        if let Some(generation) = stabilized_at {
+            info!("Stabilized at generation {}", generation);
+        }
+        info!("Generation data written to {}", output_file.display());
+        return Ok(());
+    }
 
-            let mut new_live_cells = HashSet::new();
-            for (cell, count) in neighbor_counts {
-                if count == 3 || (count == 2 && self.live_cells.contains(&cell)) {
-                    new_live_cells.insert(cell);
+    info!("Simulating");
+    let start_time = Instant::now();
+    let stabilization_kind = if !exports.is_empty() || live_view.is_some() {
+        if algorithm == Algorithm::Hashlife {
+            return Err(PowercelaError::ParseError(
+                "frame exports and --live-view are not supported with --algorithm hashlife".to_string(),
+            ));
+        }
+        #[cfg(feature = "live-view")]
+        let mut view = live_view.map(LiveView::new).transpose()?;
+        let mut rendered = vec![false; exports.len()];
+        let outcome = game.simulate_observed(iterations, |generation, cells| {
+            for (export, rendered) in exports.iter().zip(rendered.iter_mut()) {
+                if generation == export.generation {
+                    *rendered = true;
+                    let file = export.file_name(&pattern_name);
+                    match &export.format {
+                        FrameFormat::Svg(style) => render_svg_with(cells, export.cell_size, style, Path::new(&file))
+                            .map_err(|e| Interrupt::Failed(e.into()))?,
+                        FrameFormat::Png => render_png(cells, export.cell_size, Path::new(&file))
+                            .map_err(|e| Interrupt::Failed(e.into()))?,
+                    }
+                    info!("Generation {} rendered to {}", generation, file);
                 }
             }
+            #[cfg(feature = "live-view")]
+            if let Some(view) = view.as_mut() {
+                if !view.update(generation, cells).map_err(|e| Interrupt::Failed(e.into()))? {
+                    return Err(Interrupt::Stopped(generation));
+                }
+            }
+            Ok(())
+        });
+        #[cfg(feature = "live-view")]
+        drop(view);
+        for (export, _) in exports.iter().zip(&rendered).filter(|(_, &rendered)| !rendered) {
+            warn!("Generation {} was not reached, {} not written", export.generation, export.file_name(&pattern_name));
+        }
+        match outcome {
+            Ok(kind) => kind,
+            Err(Interrupt::Stopped(generation)) => {
+                info!("Simulation stopped by user at generation {}", generation);
+                StabilizationKind::None
+            }
+            Err(Interrupt::Failed(e)) => return Err(e),
+        }
+    } else if algorithm == Algorithm::Hashlife {
+        game.simulate_hashlife(iterations)
+    } else if let Some(every) = args.checkpoint_every {
+        let checkpoint_file = PathBuf::from(format!("result/{}.checkpoint.json", pattern_name));
+        info!("Saving a checkpoint to {} every {} generations", checkpoint_file.display(), every);
+        game.simulate_checkpointed(iterations, resume_from, every, &checkpoint_file)?
+    } else if config.parallel.unwrap_or(false) {
+        game.simulate_parallel(iterations)
+    } else {
+        game.simulate(iterations, resume_from)
+    };
+    let duration = start_time.elapsed();
 
-            self.live_cells = new_live_cells;
+    info!("Simulation completed in {:?}", duration);
 
-            let population = self.live_cells.len();
-            let current_gen = step + 1;
-            self.generation_data.insert(current_gen, population);
+    let top_cells = config.top_cells.map(|n| game.top_cells(n));
+    let neighbor_distribution = config.neighbor_distribution.unwrap_or(false).then(|| neighbor_count_distribution(&game.live_cells));
+    if config.track_heatmap.unwrap_or(false) {
+        let heatmap_file = PathBuf::from(format!("result/{}.heatmap.png", pattern_name));
+        write_heatmap_png(&game.compute_heatmap(), &heatmap_file)?;
+        info!("Heatmap written to {}", heatmap_file.display());
+    }
+    if let Some(PatternFormat::Life105) = args.export_format {
+        let pattern_file = PathBuf::from(format!("result/{}.lif", pattern_name));
+        fs::write(&pattern_file, export_life105(&game.live_cells, &pattern_name))?;
+        info!("Last generation written to {}", pattern_file.display());
+    }
+    let mut result = SimulationResult::from_game(game, stabilization_kind, fingerprint);
+    result.offset = offset;
+    result.neighbor_distribution_final = neighbor_distribution;
+    if let Some(top_cells) = top_cells {
+        let top_cells = top_cells.into_iter().map(|(cell, alive_generations)| TopCell { x: cell.x, y: cell.y, alive_generations });
+        result.top_cells = Some(top_cells.collect());
+        if !config.track_heatmap.unwrap_or(false) {
+            result.heatmap = None;
+        }
+    }
+    if let (Some(cells_csv), Some(snapshots)) = (&args.cells_csv, &mut result.snapshots) {
+        // Only generations asked for with --snapshot stay in the result file.
+        let requested = config.snapshot.clone().unwrap_or_default();
+        let cells: HashMap<usize, Vec<Point>> = snapshots
+            .iter()
+            .filter(|(generation, _)| cells_csv.generations.contains(generation))
+            .map(|(&generation, cells)| (generation, cells.iter().map(|&[x, y]| Point::new(x, y)).collect()))
+            .collect();
+        let cells_file = PathBuf::from(format!("result/{}.cells.csv", pattern_name));
+        write_cells_csv(&cells_file, &cells)?;
+        info!("Live cells at {} generations written to {}", cells.len(), cells_file.display());
+        snapshots.retain(|generation, _| requested.contains(generation));
+        if snapshots.is_empty() {
+            result.snapshots = None;
+        }
+    }
+    write_run_result(result, config, &pattern_name, format, compression)
+}
 
-            if current_gen >= STABILITY_WINDOW {
-                let start_gen = current_gen - STABILITY_WINDOW + 1;
-                let last_50 = self.generation_data.range(start_gen..=current_gen);
-                let is_stable = last_50.clone().all(|(_, &pop)| pop == population);
+fn write_run_result(
+    mut result: SimulationResult,
+    config: Config,
+    pattern_name: &str,
+    format: OutputFormat,
+    compression: Compression,
+) -> Result<(), PowercelaError> {
+    include_requested_series(&mut result, &config);
 
-                if is_stable {
-                    println!(
-                        "Simulation stopped at generation {}: Population stabilized at {} for 50 generations",
-                        current_gen-STABILITY_WINDOW+1, population
-                    );
-                    return Some(current_gen-STABILITY_WINDOW+1);
-                }
-            }
+    let output_file = match config.output {
+        Some(path) => {
+            write_result_to(&path, &result, format, compression)?;
+            path
+        }
+        None => write_result(&PathBuf::from(format!("result/{}", pattern_name)), &result, format, compression)?,
+    };
+    info!("Generation data written to {}", output_file.display());
+    if let StabilizationKind::MemoryLimitExceeded { generation, count } = result.stabilization_kind {
+        return Err(PowercelaError::MemoryLimitExceeded { generation, count });
+    }
+    Ok(())
+}
+
+const DIAGRAM_CELL_SIZE: u32 = 4;
+const DEFAULT_IMAGE_THRESHOLD: u8 = 128;
+
+fn run_1d(args: &RunArgs, generations: usize, output: Option<PathBuf>) -> Result<(), PowercelaError> {
+    let rule = args.rule_code.ok_or_else(|| PowercelaError::ParseError("--mode 1d requires --rule-code".to_string()))?;
+    let initial: Vec<bool> = match &args.initial_row {
+        Some(bits) => bits
+            .chars()
+            .map(|ch| match ch {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(PowercelaError::InvalidPattern(format!("unexpected character {:?} in --initial-row", ch))),
+            })
+            .collect::<Result<_, _>>()?,
+        None => vec![true],
+    };
+    let width = args.width.unwrap_or(if args.initial_row.is_some() { initial.len() } else { 128 });
+    if initial.len() > width {
+        return Err(PowercelaError::InvalidPattern(format!(
+            "initial row has {} cells but --width is {}",
+            initial.len(),
+            width
+        )));
+    }
+    let mut row = vec![false; width];
+    let start = (width - initial.len()) / 2;
+    row[start..start + initial.len()].copy_from_slice(&initial);
+
+    info!("Running elementary cellular automaton rule {}", rule);
+    info!("Width: {}, generations: {}", width, generations);
+    let rows = simulate_1d(rule, row, generations);
 
-            if current_gen % 1000 == 0 {
-                println!("Generation {}: Population = {}", current_gen, population);
+    let output_file = output.unwrap_or_else(|| PathBuf::from(format!("result/rule{}.png", rule)));
+    if let Some(parent) = output_file.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    render_rows_png(&rows, DIAGRAM_CELL_SIZE, &output_file)?;
+    info!("Space-time diagram written to {}", output_file.display());
+    Ok(())
+}
+
+// The series computed from a finished result: deltas, autocorrelation and
+// the spectrum.
+fn include_requested_series(result: &mut SimulationResult, config: &Config) {
+    if config.include_deltas.unwrap_or(false) {
+        result.include_deltas(config.delta_window.unwrap_or(DEFAULT_DELTA_WINDOW));
+    }
+    if let Some(max_lag) = config.autocorrelation {
+        result.include_autocorrelation(max_lag);
+    }
+    if config.spectrum.unwrap_or(false) {
+        result.include_spectrum();
+    }
+}
+
+fn run_batch(path: &Path, config: &Config, iterations: usize, compression: Compression) -> Result<(), PowercelaError> {
+    let split_amount = config
+        .split_amount
+        .ok_or_else(|| PowercelaError::InvalidPattern("--batch requires --split".to_string()))?;
+    let mut patterns = Vec::new();
+    for line in fs::read_to_string(path)?.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let mut game = config.builder().build()?;
+        let rows = split_pattern(line, split_amount)?;
+        game.initialize_pattern_centered(&rows.iter().map(String::as_str).collect::<Vec<_>>())?;
+        patterns.push((line.to_string(), game));
+    }
+
+    info!("Simulating {} patterns for {} iterations", patterns.len(), iterations);
+    let start_time = Instant::now();
+    let mut results = batch_simulate(patterns, iterations);
+    for (_, result) in &mut results {
+        include_requested_series(result, config);
+    }
+    info!("Batch completed in {:?}", start_time.elapsed());
+
+    fs::create_dir_all("result")?;
+    let output_file = match &config.output {
+        Some(output) => {
+            write_batch_to(output, &results, compression)?;
+            output.clone()
+        }
+        None => write_batch(Path::new("result/batch"), &results, compression)?,
+    };
+    info!("Batch results written to {}", output_file.display());
+    Ok(())
+}
+
+// `result/glider.checkpoint.json` resumes as `glider`.
+fn checkpoint_pattern_name(path: &Path) -> String {
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+    match stem.strip_suffix(".checkpoint") {
+        Some(name) => name.to_string(),
+        None => stem,
+    }
+}
+
+// Generations with a snapshot are drawn as a grid, the others as their
+// population only. Frames are printed like `info`'s output.
+fn replay(args: &ReplayArgs) -> Result<(), PowercelaError> {
+    let result = read_result(&args.path)?;
+    let frame_time = Duration::from_secs_f64(1.0 / args.replay_fps as f64);
+    if args.live_view {
+        if cfg!(not(feature = "live-view")) {
+            return Err(PowercelaError::ParseError("--live-view needs a build with the live-view feature".to_string()));
+        }
+        #[cfg(feature = "live-view")]
+        {
+            let mut view = LiveView::new(Duration::ZERO)?;
+            for frame in result.replay() {
+                let start = Instant::now();
+                let running = match &frame.cells {
+                    Some(cells) => view.update(frame.generation, cells)?,
+                    None => view.update_population(frame.generation, frame.population)?,
+                };
+                if !running {
+                    break;
+                }
+                thread::sleep(frame_time.saturating_sub(start.elapsed()));
             }
         }
+        return Ok(());
+    }
+    for frame in result.replay() {
+        let start = Instant::now();
+        println!("{}", frame);
+        thread::sleep(frame_time.saturating_sub(start.elapsed()));
+    }
+    Ok(())
+}
 
-        println!("Simulation completed all {} iterations without stabilizing", iterations);
-        None
+// `info`, `list-patterns` and `tournament` print their output rather than log it, so
+// RUST_LOG cannot hide it.
+fn info(args: &PatternArgs) -> Result<(), PowercelaError> {
+    let mut game = GameOfLife::new();
+    load_pattern(&mut game, args)?;
+    let source = if args.split.is_some() { "flat string" } else { "built-in library" };
+    println!("Source: {}", source);
+    println!("Population: {}", game.live_cells.len());
+    if let Some(bbox) = game.bounding_box() {
+        println!("Size: {}x{}", bbox.width(), bbox.height());
+        println!("Bounding box: ({}, {}) to ({}, {})", bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y);
     }
+    Ok(())
 }
 
-fn split_pattern(pattern: &str, split_amount: usize) -> Vec<String> {
-    pattern
-        .chars()
-        .collect::<Vec<char>>()
-        .chunks(split_amount)
-        .map(|chunk| chunk.iter().collect())
-        .collect()
+fn run_tournament(args: &TournamentArgs) -> Result<(), PowercelaError> {
+    let mut games = [(&args.pattern_a, GameOfLife::new()), (&args.pattern_b, GameOfLife::new())];
+    for (name, game) in &mut games {
+        game.load_named(name, 0, 0)?;
+    }
+    let [(_, a), (_, b)] = games;
+    let result = tournament_by(a, b, args.iterations, args.criterion);
+
+    println!("{:<8} {:<20} {:>12} {:>18}  Ended by", "Side", "Pattern", "Settled at", "Final population");
+    for (id, name) in [(PatternId::A, &args.pattern_a), (PatternId::B, &args.pattern_b)] {
+        let side = result.result(id);
+        let settled_at = match side.stabilized_at {
+            Some(generation) if side.stabilization_kind.is_settled() => generation.to_string(),
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:<8} {:<20} {:>12} {:>18}  {:?}",
+            format!("{:?}", id),
+            name,
+            settled_at,
+            result.final_population(id),
+            side.stabilization_kind
+        );
+    }
+    match result.winner {
+        Some(PatternId::A) => println!("Winner by {}: {}", result.winner_by, args.pattern_a),
+        Some(PatternId::B) => println!("Winner by {}: {}", result.winner_by, args.pattern_b),
+        None => println!("No winner by {}", result.winner_by),
+    }
+    Ok(())
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 4 {
-        eprintln!("Usage: <iterations> <split_amount> <pattern_to_split>");
-        eprintln!("Example: cellula.exe 10000 9 011000000100100000110000000111010000000000100110000111011100110001111100000011000");
-        return;
+fn init_config() -> Result<(), PowercelaError> {
+    // Refuses to overwrite an existing config.
+    File::create_new(DEFAULT_CONFIG_FILE)?.write_all(EXAMPLE_CONFIG.as_bytes())?;
+    info!("Wrote {}", DEFAULT_CONFIG_FILE);
+    Ok(())
+}
+
+fn list_patterns(args: &ListPatternsArgs) {
+    for pattern in PatternLibrary::search(args.query()) {
+        let period = pattern.period.map_or_else(String::new, |period| format!(", period {}", period));
+        println!(
+            "{:<20} {:>3}x{:<3} {:>3} cells  {}{}",
+            pattern.name,
+            pattern.width,
+            pattern.height(),
+            pattern.population(),
+            pattern.category,
+            period
+        );
     }
+}
 
-    let iterations: usize = args[1].parse().expect("Invalid iterations");
-    let split_amount: usize = args[2].parse().expect("Invalid split amount");
-    let pattern_to_split = &args[3];
+// A flat 0/1 string when a split amount is given, otherwise a library pattern name.
+fn load_pattern(game: &mut GameOfLife, args: &PatternArgs) -> Result<String, PowercelaError> {
+    match args.split {
+        Some(split_amount) => load_flat_pattern(game, &args.pattern, split_amount)?,
+        None => {
+            info!("Pattern: {}", args.pattern);
+            game.load_named(&args.pattern, 0, 0).map_err(|e| {
+                PowercelaError::InvalidPattern(format!("{}; pass --split to load a flat 0/1 string", e))
+            })?;
+        }
+    }
+    Ok(args.pattern.clone())
+}
 
-    let pattern = split_pattern(pattern_to_split, split_amount);
+fn parse_number(value: &str, what: &str) -> Result<usize, PowercelaError> {
+    value
+        .parse()
+        .map_err(|_| PowercelaError::ParseError(format!("invalid {}: {:?}", what, value)))
+}
 
-    println!("Running Conway's Game of Life in Rust");
-    println!("Iterations: {}", iterations);
-    println!("Split Amount: {}", split_amount);
-    println!("Pattern: {:?}", pattern);
+// Digits may be grouped with underscores, as in 10_000_000.
+fn parse_cell_count(value: &str) -> Result<usize, PowercelaError> {
+    parse_number(&value.replace('_', ""), "cell count")
+}
 
-    let mut game = GameOfLife::new();
-    let start_x = -(split_amount as i32) / 2;
-    let start_y = -(pattern.len() as i32) / 2;
+fn load_flat_pattern(game: &mut GameOfLife, pattern_to_split: &str, split_amount: usize) -> Result<(), PowercelaError> {
+    let pattern = split_pattern(pattern_to_split, split_amount)?;
 
-    println!("Setting up pattern centered at ({}, {})", start_x, start_y);
-    game.initialize_pattern(&pattern.iter().map(|s| s.as_str()).collect::<Vec<_>>(), start_x, start_y);
+    debug!("Split Amount: {}", split_amount);
+    debug!("Pattern: {:?}", pattern);
 
-    println!("Simulating");
-    let start_time = Instant::now();
-    let stabilized_at = game.simulate(iterations);
-    let duration = start_time.elapsed();
+    game.initialize_pattern_at_origin(&pattern.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+}
 
-    println!("Simulation completed in {:?}", duration);
+// Why an observed run ended before its last iteration.
+enum Interrupt {
+    #[cfg_attr(not(feature = "live-view"), allow(dead_code))]
+    Stopped(usize),
+    Failed(PowercelaError),
+}
 
-    fs::create_dir_all("result").unwrap();
+fn parse_autocorrelation(spec: &str) -> Result<usize, PowercelaError> {
+    spec.strip_prefix("max-lag=").and_then(|lag| lag.parse().ok()).ok_or_else(|| {
+        PowercelaError::ParseError(format!("invalid autocorrelation spec {:?}, expected max-lag=<n>", spec))
+    })
+}
 
-    let result = SimulationResult {
-        generations: game.generation_data,
-        stabilized_at,
-    };
+#[derive(Clone)]
+struct RuleList(Vec<Rule>);
 
-    let json_data = serde_json::to_string_pretty(&result).unwrap();
-    let output_file = format!("result/{}.json", args.last().unwrap());
-    fs::write(&output_file, json_data).unwrap();
-    println!("Generation data written to {}", output_file);
-}
\ No newline at end of file
+// Commas separate rules, except inside a rule's count list like B3,10/S23:
+// a part that does not start with B belongs to the rule before it.
+fn parse_rule_list(spec: &str) -> Result<RuleList, PowercelaError> {
+    let mut rules: Vec<String> = Vec::new();
+    for part in spec.split(',') {
+        match rules.last_mut() {
+            Some(rule) if !part.starts_with(['B', 'b']) => {
+                rule.push(',');
+                rule.push_str(part);
+            }
+            _ => rules.push(part.to_string()),
+        }
+    }
+    Ok(RuleList(rules.iter().map(|rule| rule.parse()).collect::<Result<_, _>>()?))
+}
+
+#[derive(Clone)]
+struct CellsCsv {
+    generations: Vec<usize>,
+}
+
+fn parse_cells_csv(spec: &str) -> Result<CellsCsv, PowercelaError> {
+    let list = spec.strip_prefix("generations=").ok_or_else(|| {
+        PowercelaError::ParseError(format!("invalid cells CSV spec {:?}, expected generations=<n>,<n>,...", spec))
+    })?;
+    let generations = list.split(',').map(|generation| parse_number(generation.trim(), "generation")).collect::<Result<_, _>>()?;
+    Ok(CellsCsv { generations })
+}
+
+#[derive(Clone)]
+struct FrameExport {
+    generation: usize,
+    cell_size: u32,
+    format: FrameFormat,
+}
+
+#[derive(Clone)]
+enum FrameFormat {
+    Svg(SvgStyle),
+    Png,
+}
+
+impl FrameExport {
+    fn file_name(&self, pattern_name: &str) -> String {
+        let extension = match self.format {
+            FrameFormat::Svg(_) => "svg",
+            FrameFormat::Png => "png",
+        };
+        format!("result/{}_gen{}.{}", pattern_name, self.generation, extension)
+    }
+}
+
+fn parse_svg_export(spec: &str) -> Result<FrameExport, PowercelaError> {
+    parse_frame_export(spec, FrameFormat::Svg(SvgStyle::default()))
+}
+
+fn parse_png_export(spec: &str) -> Result<FrameExport, PowercelaError> {
+    parse_frame_export(spec, FrameFormat::Png)
+}
+
+// Parses `generation=100,cell_size=4` plus, for SVG, `fill=`, `background=`,
+// `stroke=` and `stroke_width=`.
+fn parse_frame_export(spec: &str, mut format: FrameFormat) -> Result<FrameExport, PowercelaError> {
+    let mut cell_size = match format {
+        FrameFormat::Svg(_) => 4,
+        FrameFormat::Png => 1,
+    };
+    let mut generation = None;
+    for part in spec.split(',') {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| PowercelaError::ParseError(format!("invalid export option {:?}, expected key=value", part)))?;
+        match (key, &mut format) {
+            ("generation", _) => generation = Some(parse_number(value, "export generation")?),
+            ("cell_size", _) => {
+                cell_size = value.parse().ok().filter(|size| CELL_SIZES.contains(size)).ok_or_else(|| {
+                    PowercelaError::ParseError(format!("invalid cell size {:?}, expected 1, 2, 4, 8, 16 or 32", value))
+                })?
+            }
+            ("fill", FrameFormat::Svg(style)) => style.fill = value.to_string(),
+            ("background", FrameFormat::Svg(style)) => style.background = value.to_string(),
+            ("stroke", FrameFormat::Svg(style)) => style.stroke = value.to_string(),
+            ("stroke_width", FrameFormat::Svg(style)) => {
+                style.stroke_width = value
+                    .parse()
+                    .map_err(|_| PowercelaError::ParseError(format!("invalid SVG stroke width: {:?}", value)))?
+            }
+            _ => return Err(PowercelaError::ParseError(format!("unknown export option {:?}", key))),
+        }
+    }
+    let generation = generation.ok_or_else(|| PowercelaError::ParseError("export needs generation=<n>".to_string()))?;
+    Ok(FrameExport { generation, cell_size, format })
+}
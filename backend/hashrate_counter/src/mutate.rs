@@ -0,0 +1,25 @@
+use crate::{GameOfLife, Point};
+
+impl GameOfLife {
+    // Makes the cell at (x, y) alive or dead, wrapped onto the torus if there
+    // is one.
+    pub fn set_cell(&mut self, x: i64, y: i64, alive: bool) {
+        let cell = self.topology().wrap(Point::new(x, y));
+        if alive {
+            self.live_cells.insert(cell);
+        } else {
+            self.live_cells.remove(&cell);
+        }
+    }
+
+    // Removes `died` and then adds `born`, e.g. one line of `simulate_diff`
+    // output, so a cell in both ends up alive.
+    pub fn apply_diff(&mut self, born: &[Point], died: &[Point]) {
+        let topology = self.topology();
+        for cell in died {
+            self.live_cells.remove(&topology.wrap(*cell));
+        }
+        self.live_cells.reserve(born.len());
+        self.live_cells.extend(born.iter().map(|&cell| topology.wrap(cell)));
+    }
+}
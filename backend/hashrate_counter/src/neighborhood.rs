@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{BuildError, GameOfLife, Point, PowercelaError};
+
+// Which cells count as neighbors, as offsets from the cell itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Neighborhood {
+    #[default]
+    Moore,
+    VonNeumann,
+    // Every cell within the square of radius r.
+    MooreRadius(u8),
+    // Every cell within Manhattan distance r.
+    VonNeumannRadius(u8),
+    Custom(Vec<(i32, i32)>),
+}
+
+impl Neighborhood {
+    pub fn offsets(&self) -> Vec<(i64, i64)> {
+        match self {
+            Neighborhood::Moore => vec![
+                (-1, -1), (-1, 0), (-1, 1),
+                (0, -1),           (0, 1),
+                (1, -1),  (1, 0),  (1, 1),
+            ],
+            Neighborhood::VonNeumann => vec![(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighborhood::MooreRadius(r) => square(*r as i64).collect(),
+            Neighborhood::VonNeumannRadius(r) => {
+                let r = *r as i64;
+                square(r).filter(|(dx, dy)| dx.abs() + dy.abs() <= r).collect()
+            }
+            Neighborhood::Custom(offsets) => offsets.iter().map(|&(dx, dy)| (dx as i64, dy as i64)).collect(),
+        }
+    }
+
+    // The largest neighbor count a rule can see.
+    pub fn max_neighbors(&self) -> usize {
+        match self {
+            Neighborhood::Moore => 8,
+            Neighborhood::VonNeumann => 4,
+            Neighborhood::MooreRadius(r) => (2 * *r as usize + 1).pow(2) - 1,
+            Neighborhood::VonNeumannRadius(r) => 2 * *r as usize * (*r as usize + 1),
+            Neighborhood::Custom(offsets) => offsets.len(),
+        }
+    }
+
+    // `max_neighbors`, or `BuildError::NeighborhoodTooLarge` past 255, the
+    // most the u8 neighbor counts can hold.
+    pub fn checked_max_neighbors(&self) -> Result<usize, BuildError> {
+        let max_neighbors = self.max_neighbors();
+        if max_neighbors > u8::MAX as usize {
+            return Err(BuildError::NeighborhoodTooLarge(max_neighbors));
+        }
+        Ok(max_neighbors)
+    }
+
+    // Hashlife and the spatial index hard-code the radius 1 Moore neighborhood.
+    pub fn is_moore(&self) -> bool {
+        matches!(self, Neighborhood::Moore | Neighborhood::MooreRadius(1))
+    }
+}
+
+fn square(r: i64) -> impl Iterator<Item = (i64, i64)> {
+    (-r..=r).flat_map(move |dx| (-r..=r).map(move |dy| (dx, dy))).filter(|&offset| offset != (0, 0))
+}
+
+// "moore", "vonneumann", or either with a radius such as "moore:2".
+impl FromStr for Neighborhood {
+    type Err = PowercelaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || PowercelaError::ParseError(format!("invalid neighborhood {:?}, expected moore[:r] or vonneumann[:r]", s));
+        let (name, radius) = match s.split_once(':') {
+            Some((name, radius)) => (name, Some(radius.parse::<u8>().ok().filter(|&r| r > 0).ok_or_else(invalid)?)),
+            None => (s, None),
+        };
+        match (name, radius) {
+            ("moore", None | Some(1)) => Ok(Neighborhood::Moore),
+            ("moore", Some(r)) => Ok(Neighborhood::MooreRadius(r)),
+            ("vonneumann", None | Some(1)) => Ok(Neighborhood::VonNeumann),
+            ("vonneumann", Some(r)) => Ok(Neighborhood::VonNeumannRadius(r)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for Neighborhood {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Neighborhood::Moore => write!(f, "moore"),
+            Neighborhood::VonNeumann => write!(f, "vonneumann"),
+            Neighborhood::MooreRadius(r) => write!(f, "moore:{}", r),
+            Neighborhood::VonNeumannRadius(r) => write!(f, "vonneumann:{}", r),
+            Neighborhood::Custom(offsets) => write!(f, "custom ({} neighbors)", offsets.len()),
+        }
+    }
+}
+
+// Entry k is the number of live cells with exactly k live Moore neighbors.
+// Neighbors do not wrap around a torus.
+pub fn neighbor_count_distribution(cells: &HashSet<Point>) -> [usize; 9] {
+    let moore = Neighborhood::Moore.offsets();
+    let mut distribution = [0; 9];
+    for cell in cells {
+        let neighbors = moore
+            .iter()
+            .filter(|&&(dx, dy)| cells.contains(&Point::new(cell.x.wrapping_add(dx), cell.y.wrapping_add(dy))))
+            .count();
+        distribution[neighbors] += 1;
+    }
+    distribution
+}
+
+impl GameOfLife {
+    pub fn with_neighborhood(neighborhood: Neighborhood) -> Result<Self, BuildError> {
+        let mut game = Self::new();
+        game.set_neighborhood(neighborhood)?;
+        Ok(game)
+    }
+
+    pub fn neighborhood(&self) -> &Neighborhood {
+        &self.neighborhood
+    }
+
+    // Hashlife and the spatial index only know the Moore neighborhood, so any
+    // other one runs on the plain stepper. Neighbor counts are kept in a u8,
+    // so neighborhoods larger than 255 are rejected and the current one kept.
+    pub fn set_neighborhood(&mut self, neighborhood: Neighborhood) -> Result<(), BuildError> {
+        neighborhood.checked_max_neighbors()?;
+        self.neighbor_offsets = neighborhood.offsets();
+        self.neighborhood = neighborhood;
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use parquet::basic::{Compression as ParquetCompression, GzipLevel};
+use parquet::data_type::{Int32Type, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+use serde::Serialize;
+
+use crate::{GameOfLife, Point, PowercelaError, SimulationResult};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    Json,
+    Csv,
+    Parquet,
+}
+
+impl OutputFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Parquet => "parquet",
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = PowercelaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            "parquet" => Ok(OutputFormat::Parquet),
+            _ => Err(PowercelaError::ParseError(format!("output format must be csv, json or parquet, got {:?}", s))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Compression {
+    None,
+    Gzip(flate2::Compression),
+}
+
+pub fn write_csv(path: &Path, result: &SimulationResult) -> io::Result<()> {
+    write_csv_to(BufWriter::new(File::create(path)?), result)
+}
+
+fn write_csv_to<W: Write>(mut writer: W, result: &SimulationResult) -> io::Result<()> {
+    writeln!(writer, "generation,population")?;
+    for (generation, population) in &result.generations {
+        writeln!(writer, "{},{}", generation, population)?;
+    }
+    if let Some(stabilized_at) = result.stabilized_at {
+        writeln!(writer, "# stabilized_at,{}", stabilized_at)?;
+    }
+    writer.flush()
+}
+
+// One `generation,x,y` row per live cell of every snapshot, in generation
+// order and then in the order of each snapshot's cells.
+pub fn write_cells_csv(path: &Path, snapshots: &HashMap<usize, Vec<Point>>) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "generation,x,y")?;
+    let mut generations: Vec<&usize> = snapshots.keys().collect();
+    generations.sort_unstable();
+    for generation in generations {
+        for cell in &snapshots[generation] {
+            writeln!(writer, "{},{},{}", generation, cell.x, cell.y)?;
+        }
+    }
+    writer.flush()
+}
+
+// Writes `generation` and `population` INT64 columns to `path`. A Parquet file
+// has a single schema, so snapshots go to a second file next to it,
+// `<stem>.snapshots.parquet`, with one `generation`, `x`, `y` row per cell.
+pub fn write_parquet(result: &SimulationResult, path: &Path) -> Result<(), PowercelaError> {
+    write_parquet_with(result, path, Compression::None)
+}
+
+fn write_parquet_with(result: &SimulationResult, path: &Path, compression: Compression) -> Result<(), PowercelaError> {
+    let compression = match compression {
+        Compression::None => ParquetCompression::UNCOMPRESSED,
+        Compression::Gzip(level) => ParquetCompression::GZIP(GzipLevel::try_new(level.level())?),
+    };
+    let properties = Arc::new(WriterProperties::builder().set_compression(compression).build());
+
+    let generations: Vec<i64> = result.generations.keys().map(|&generation| generation as i64).collect();
+    let populations: Vec<i64> = result.generations.values().map(|&population| population as i64).collect();
+    let schema = "message generations { REQUIRED INT64 generation; REQUIRED INT64 population; }";
+    let mut writer = SerializedFileWriter::new(File::create(path)?, Arc::new(parse_message_type(schema)?), properties.clone())?;
+    let mut row_group = writer.next_row_group()?;
+    for values in [&generations, &populations] {
+        let mut column = row_group.next_column()?.expect("schema has two columns");
+        column.typed::<Int64Type>().write_batch(values, None, None)?;
+        column.close()?;
+    }
+    row_group.close()?;
+    writer.close()?;
+
+    let Some(snapshots) = &result.snapshots else {
+        return Ok(());
+    };
+    let mut generations = Vec::new();
+    let (mut xs, mut ys) = (Vec::new(), Vec::new());
+    for (&generation, cells) in snapshots {
+        for &[x, y] in cells {
+            let coordinate = |value: i64| {
+                i32::try_from(value).map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidInput, format!("snapshot cell ({}, {}) does not fit in INT32", x, y))
+                })
+            };
+            generations.push(generation as i64);
+            xs.push(coordinate(x)?);
+            ys.push(coordinate(y)?);
+        }
+    }
+    let schema = "message snapshots { REQUIRED INT64 generation; REQUIRED INT32 x; REQUIRED INT32 y; }";
+    let file = File::create(path.with_extension("snapshots.parquet"))?;
+    let mut writer = SerializedFileWriter::new(file, Arc::new(parse_message_type(schema)?), properties)?;
+    let mut row_group = writer.next_row_group()?;
+    let mut column = row_group.next_column()?.expect("schema has three columns");
+    column.typed::<Int64Type>().write_batch(&generations, None, None)?;
+    column.close()?;
+    for values in [&xs, &ys] {
+        let mut column = row_group.next_column()?.expect("schema has three columns");
+        column.typed::<Int32Type>().write_batch(values, None, None)?;
+        column.close()?;
+    }
+    row_group.close()?;
+    writer.close()?;
+    Ok(())
+}
+
+fn write_formatted(writer: &mut dyn Write, result: &SimulationResult, format: OutputFormat) -> Result<(), PowercelaError> {
+    match format {
+        OutputFormat::Json => serde_json::to_writer_pretty(writer, result)?,
+        OutputFormat::Csv => write_csv_to(writer, result)?,
+        OutputFormat::Parquet => unreachable!("parquet is written by write_parquet"),
+    }
+    Ok(())
+}
+
+// Hands `write` the file, behind a gzip encoder when compressed, and flushes it.
+fn write_compressed(
+    path: &Path,
+    compression: Compression,
+    write: impl FnOnce(&mut dyn Write) -> Result<(), PowercelaError>,
+) -> Result<(), PowercelaError> {
+    let mut file = BufWriter::new(File::create(path)?);
+    match compression {
+        Compression::None => {
+            write(&mut file)?;
+            file.flush()?;
+        }
+        Compression::Gzip(level) => {
+            let mut encoder = GzEncoder::new(file, level);
+            write(&mut encoder)?;
+            encoder.finish()?.flush()?;
+        }
+    }
+    Ok(())
+}
+
+// `stem` plus the extension, and `.gz` when compressed.
+fn output_path(stem: &Path, extension: &str, compression: Compression) -> PathBuf {
+    let mut path = OsString::from(stem);
+    path.push(".");
+    path.push(extension);
+    if let Compression::Gzip(_) = compression {
+        path.push(".gz");
+    }
+    PathBuf::from(path)
+}
+
+// Writes `result` to `stem` plus the format's extension (and `.gz` when
+// compressed, except for Parquet, which compresses its pages instead),
+// returning the path that was written.
+pub fn write_result(
+    stem: &Path,
+    result: &SimulationResult,
+    format: OutputFormat,
+    compression: Compression,
+) -> Result<PathBuf, PowercelaError> {
+    let suffix = if format == OutputFormat::Parquet { Compression::None } else { compression };
+    let path = output_path(stem, format.extension(), suffix);
+    write_result_to(&path, result, format, compression)?;
+    Ok(path)
+}
+
+// Like `write_result`, but writes to exactly `path`.
+pub fn write_result_to(
+    path: &Path,
+    result: &SimulationResult,
+    format: OutputFormat,
+    compression: Compression,
+) -> Result<(), PowercelaError> {
+    match format {
+        OutputFormat::Parquet => write_parquet_with(result, path, compression),
+        _ => write_compressed(path, compression, |writer| write_formatted(writer, result, format)),
+    }
+}
+
+// Reads a JSON result written by `write_result`, gunzipping it first when the
+// file name ends in `.gz`.
+pub fn read_result(path: &Path) -> Result<SimulationResult, PowercelaError> {
+    let file = BufReader::new(File::open(path)?);
+    let reader: Box<dyn Read> = match path.extension() {
+        Some(extension) if extension == "gz" => Box::new(GzDecoder::new(file)),
+        _ => Box::new(file),
+    };
+    Ok(serde_json::from_reader(reader)?)
+}
+
+#[derive(Serialize)]
+struct DiffLine {
+    #[serde(rename = "gen")]
+    generation: usize,
+    born: Vec<[i64; 2]>,
+    died: Vec<[i64; 2]>,
+}
+
+// The cells of `cells` missing from `other`, sorted.
+fn missing_from(cells: &HashSet<Point>, other: &HashSet<Point>) -> Vec<[i64; 2]> {
+    let mut missing: Vec<Point> = cells.difference(other).copied().collect();
+    missing.sort();
+    missing.into_iter().map(|cell| [cell.x, cell.y]).collect()
+}
+
+#[derive(Serialize)]
+struct BatchEntry<'a> {
+    name: &'a str,
+    result: &'a SimulationResult,
+}
+
+// Batch results are always a JSON array of `{"name": ..., "result": ...}` objects.
+pub fn write_batch(
+    stem: &Path,
+    results: &[(String, SimulationResult)],
+    compression: Compression,
+) -> Result<PathBuf, PowercelaError> {
+    let path = output_path(stem, OutputFormat::Json.extension(), compression);
+    write_batch_to(&path, results, compression)?;
+    Ok(path)
+}
+
+pub fn write_batch_to(
+    path: &Path,
+    results: &[(String, SimulationResult)],
+    compression: Compression,
+) -> Result<(), PowercelaError> {
+    let entries: Vec<BatchEntry> = results.iter().map(|(name, result)| BatchEntry { name, result }).collect();
+    write_compressed(path, compression, |writer| Ok(serde_json::to_writer_pretty(writer, &entries)?))
+}
+
+impl GameOfLife {
+    // Writes one `{"generation": N, "population": P}` line per generation as the
+    // run goes instead of filling `generation_data`, so memory stays flat on
+    // long runs. Returns the generation the run stabilized at, if any.
+    pub fn simulate_streaming<W: Write>(&mut self, mut writer: W, iterations: usize) -> io::Result<Option<usize>> {
+        let kind = self.run_with(iterations, 0, Self::next_generation, |generation, cells| {
+            writeln!(writer, "{{\"generation\": {}, \"population\": {}}}", generation, cells.len())
+        })?;
+        writer.flush()?;
+        Ok(kind.generation())
+    }
+
+    // Like `simulate_streaming`, but each line is `{"gen": N, "born": [[x, y],
+    // ...], "died": [...]}` with the cells that changed since the previous
+    // generation. Generation 0 lists every starting cell as born, so applying
+    // the lines in order rebuilds any generation.
+    pub fn simulate_diff<W: Write>(&mut self, mut writer: W, iterations: usize) -> io::Result<Option<usize>> {
+        let mut previous = HashSet::new();
+        let kind = self.run_with(iterations, 0, Self::next_generation, |generation, cells| {
+            let line = DiffLine { generation, born: missing_from(cells, &previous), died: missing_from(&previous, cells) };
+            serde_json::to_writer(&mut writer, &line)?;
+            writeln!(writer)?;
+            previous.clone_from(cells);
+            Ok::<(), io::Error>(())
+        })?;
+        writer.flush()?;
+        Ok(kind.generation())
+    }
+}
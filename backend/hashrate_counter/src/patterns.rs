@@ -0,0 +1,327 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::{GameOfLife, PowercelaError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedPattern {
+    pub name: &'static str,
+    pub width: usize,
+    pub rows: &'static [&'static str],
+    pub category: PatternCategory,
+    // Generations until the pattern repeats, moved for spaceships; 1 for still
+    // lifes and `None` for patterns that never repeat.
+    pub period: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternCategory {
+    StillLife,
+    Oscillator,
+    Spaceship,
+    Gun,
+    // Methuselahs and anything else that settles or grows irregularly.
+    Other,
+}
+
+// "still-life", "oscillator", "spaceship", "gun" or "other".
+impl FromStr for PatternCategory {
+    type Err = PowercelaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('_', "-").as_str() {
+            "still-life" => Ok(PatternCategory::StillLife),
+            "oscillator" => Ok(PatternCategory::Oscillator),
+            "spaceship" => Ok(PatternCategory::Spaceship),
+            "gun" => Ok(PatternCategory::Gun),
+            "other" => Ok(PatternCategory::Other),
+            _ => Err(PowercelaError::ParseError(format!(
+                "invalid pattern category {:?}, expected still-life, oscillator, spaceship, gun or other",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for PatternCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PatternCategory::StillLife => "still-life",
+            PatternCategory::Oscillator => "oscillator",
+            PatternCategory::Spaceship => "spaceship",
+            PatternCategory::Gun => "gun",
+            PatternCategory::Other => "other",
+        };
+        f.write_str(name)
+    }
+}
+
+// Criteria for `PatternLibrary::search`; every one that is set has to match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PatternQuery {
+    period: Option<usize>,
+    category: Option<PatternCategory>,
+    min_cells: Option<usize>,
+    max_cells: Option<usize>,
+    name_contains: Option<String>,
+}
+
+impl PatternQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn period(mut self, period: usize) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    pub fn category(mut self, category: PatternCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn min_cells(mut self, min_cells: usize) -> Self {
+        self.min_cells = Some(min_cells);
+        self
+    }
+
+    pub fn max_cells(mut self, max_cells: usize) -> Self {
+        self.max_cells = Some(max_cells);
+        self
+    }
+
+    // Ignores case, like `PatternLibrary::get`.
+    pub fn name_contains(mut self, part: &str) -> Self {
+        self.name_contains = Some(part.to_ascii_lowercase());
+        self
+    }
+
+    pub fn matches(&self, pattern: &NamedPattern) -> bool {
+        let population = pattern.population();
+        self.period.is_none_or(|period| pattern.period == Some(period))
+            && self.category.is_none_or(|category| pattern.category == category)
+            && self.min_cells.is_none_or(|min| population >= min)
+            && self.max_cells.is_none_or(|max| population <= max)
+            && self.name_contains.as_ref().is_none_or(|part| pattern.name.to_ascii_lowercase().contains(part))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown pattern {0:?}")]
+pub struct UnknownPattern(pub String);
+
+pub struct PatternLibrary;
+
+impl PatternLibrary {
+    pub fn all() -> &'static [NamedPattern] {
+        PATTERNS
+    }
+
+    pub fn get(name: &str) -> Option<&'static NamedPattern> {
+        PATTERNS.iter().find(|pattern| pattern.name.eq_ignore_ascii_case(name))
+    }
+
+    // In library order.
+    pub fn search(query: PatternQuery) -> Vec<&'static NamedPattern> {
+        PATTERNS.iter().filter(|pattern| query.matches(pattern)).collect()
+    }
+}
+
+impl NamedPattern {
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    // Live cells in `rows`.
+    pub fn population(&self) -> usize {
+        self.rows.iter().map(|row| row.matches('1').count()).sum()
+    }
+}
+
+impl GameOfLife {
+    pub fn load_named(&mut self, name: &str, cx: i64, cy: i64) -> Result<(), UnknownPattern> {
+        let pattern = PatternLibrary::get(name).ok_or_else(|| UnknownPattern(name.to_string()))?;
+        let start_x = cx - pattern.width as i64 / 2;
+        let start_y = cy - pattern.height() as i64 / 2;
+        self.insert_rows(pattern.rows, start_x, start_y);
+        Ok(())
+    }
+}
+
+const PATTERNS: &[NamedPattern] = &[
+    NamedPattern {
+        name: "glider",
+        width: 3,
+        rows: &[
+            "010",
+            "001",
+            "111",
+        ],
+        category: PatternCategory::Spaceship,
+        period: Some(4),
+    },
+    NamedPattern {
+        name: "blinker",
+        width: 3,
+        rows: &[
+            "111",
+        ],
+        category: PatternCategory::Oscillator,
+        period: Some(2),
+    },
+    NamedPattern {
+        name: "toad",
+        width: 4,
+        rows: &[
+            "0111",
+            "1110",
+        ],
+        category: PatternCategory::Oscillator,
+        period: Some(2),
+    },
+    NamedPattern {
+        name: "beacon",
+        width: 4,
+        rows: &[
+            "1100",
+            "1100",
+            "0011",
+            "0011",
+        ],
+        category: PatternCategory::Oscillator,
+        period: Some(2),
+    },
+    NamedPattern {
+        name: "pulsar",
+        width: 13,
+        rows: &[
+            "0011100011100",
+            "0000000000000",
+            "1000010100001",
+            "1000010100001",
+            "1000010100001",
+            "0011100011100",
+            "0000000000000",
+            "0011100011100",
+            "1000010100001",
+            "1000010100001",
+            "1000010100001",
+            "0000000000000",
+            "0011100011100",
+        ],
+        category: PatternCategory::Oscillator,
+        period: Some(3),
+    },
+    NamedPattern {
+        name: "lwss",
+        width: 5,
+        rows: &[
+            "01001",
+            "10000",
+            "10001",
+            "11110",
+        ],
+        category: PatternCategory::Spaceship,
+        period: Some(4),
+    },
+    NamedPattern {
+        name: "mwss",
+        width: 6,
+        rows: &[
+            "000100",
+            "010001",
+            "100000",
+            "100001",
+            "111110",
+        ],
+        category: PatternCategory::Spaceship,
+        period: Some(4),
+    },
+    NamedPattern {
+        name: "hwss",
+        width: 7,
+        rows: &[
+            "0001100",
+            "0100001",
+            "1000000",
+            "1000001",
+            "1111110",
+        ],
+        category: PatternCategory::Spaceship,
+        period: Some(4),
+    },
+    NamedPattern {
+        name: "r-pentomino",
+        width: 3,
+        rows: &[
+            "011",
+            "110",
+            "010",
+        ],
+        category: PatternCategory::Other,
+        period: None,
+    },
+    NamedPattern {
+        name: "diehard",
+        width: 8,
+        rows: &[
+            "00000010",
+            "11000000",
+            "01000111",
+        ],
+        category: PatternCategory::Other,
+        period: None,
+    },
+    NamedPattern {
+        name: "acorn",
+        width: 7,
+        rows: &[
+            "0100000",
+            "0001000",
+            "1100111",
+        ],
+        category: PatternCategory::Other,
+        period: None,
+    },
+    NamedPattern {
+        name: "gosper-glider-gun",
+        width: 36,
+        rows: &[
+            "000000000000000000000000100000000000",
+            "000000000000000000000010100000000000",
+            "000000000000110000001100000000000011",
+            "000000000001000100001100000000000011",
+            "110000000010000010001100000000000000",
+            "110000000010001011000010100000000000",
+            "000000000010000010000000100000000000",
+            "000000000001000100000000000000000000",
+            "000000000000110000000000000000000000",
+        ],
+        category: PatternCategory::Gun,
+        period: Some(30),
+    },
+    NamedPattern {
+        name: "block",
+        width: 2,
+        rows: &[
+            "11",
+            "11",
+        ],
+        category: PatternCategory::StillLife,
+        period: Some(1),
+    },
+    NamedPattern {
+        name: "beehive",
+        width: 4,
+        rows: &[
+            "0110",
+            "1001",
+            "0110",
+        ],
+        category: PatternCategory::StillLife,
+        period: Some(1),
+    },
+];
@@ -0,0 +1,93 @@
+use std::collections::{BTreeMap, HashSet};
+
+use rand::rngs::Xoshiro256PlusPlus;
+use rand::{Rng, RngExt, SeedableRng};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{BoundingBox, GameOfLife, Point, Topology};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SensitivityReport {
+    pub perturbations: usize,
+    pub flips: usize,
+    // Mean number of cells in which a perturbed copy differs from the base
+    // pattern, per generation starting at 0.
+    #[serde(rename = "meanHammingDistance")]
+    pub mean_hamming_distance: BTreeMap<usize, f64>,
+}
+
+impl GameOfLife {
+    // A copy with `n` distinct cells flipped, chosen from the bounding box
+    // grown by one cell on every side, so births can appear next to the
+    // pattern. Rule, topology, neighborhood and limits are kept; tracking,
+    // history and callbacks are not. An empty pattern is copied unchanged.
+    pub fn perturb(&self, n: usize, rng: &mut impl Rng) -> GameOfLife {
+        let mut copy = self.copy_settings();
+        copy.live_cells = self.live_cells.clone();
+        let Some(bbox) = BoundingBox::from_cells(&self.live_cells) else {
+            return copy;
+        };
+        let (min_x, min_y) = (bbox.min_x.saturating_sub(1), bbox.min_y.saturating_sub(1));
+        let (max_x, max_y) = (bbox.max_x.saturating_add(1), bbox.max_y.saturating_add(1));
+        let (mut columns, mut rows) = (max_x as i128 - min_x as i128 + 1, max_y as i128 - min_y as i128 + 1);
+        if let Topology::Torus { width, height } = self.topology {
+            (columns, rows) = (columns.min(width as i128), rows.min(height as i128));
+        }
+        let area = columns * rows;
+
+        let mut flipped = HashSet::new();
+        while (flipped.len() as i128) < area.min(n as i128) {
+            let cell = self.topology.wrap(Point::new(rng.random_range(min_x..=max_x), rng.random_range(min_y..=max_y)));
+            if flipped.insert(cell) && !copy.live_cells.remove(&cell) {
+                copy.live_cells.insert(cell);
+            }
+        }
+        copy
+    }
+
+    fn copy_settings(&self) -> GameOfLife {
+        GameOfLife {
+            topology: self.topology,
+            stability_window: self.stability_window,
+            max_population: self.max_population,
+            max_cells: self.max_cells,
+            rule: self.rule.clone(),
+            neighborhood: self.neighborhood.clone(),
+            neighbor_offsets: self.neighbor_offsets.clone(),
+            spatial_index: self.spatial_index,
+            quiet: self.quiet,
+            progress_bar: self.progress_bar,
+            ..GameOfLife::new()
+        }
+    }
+}
+
+// Steps the base pattern and `n_perturbations` copies with `n_flips` flips each
+// for `iterations` generations, with no stability checks. The same seed gives
+// the same report.
+pub fn sensitivity_analysis(
+    base: &GameOfLife,
+    n_perturbations: usize,
+    n_flips: usize,
+    iterations: usize,
+    seed: u64,
+) -> SensitivityReport {
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let mut original = base.perturb(0, &mut rng);
+    let mut copies: Vec<GameOfLife> = (0..n_perturbations).map(|_| base.perturb(n_flips, &mut rng)).collect();
+
+    let mut mean_hamming_distance = BTreeMap::new();
+    for generation in 0..=iterations {
+        if generation > 0 {
+            original.step_generation(generation, GameOfLife::next_generation);
+            copies.par_iter_mut().for_each(|copy| copy.step_generation(generation, GameOfLife::next_generation));
+        }
+        let total: usize = copies
+            .par_iter()
+            .map(|copy| copy.live_cells.symmetric_difference(&original.live_cells).count())
+            .sum();
+        mean_hamming_distance.insert(generation, total as f64 / n_perturbations.max(1) as f64);
+    }
+    SensitivityReport { perturbations: n_perturbations, flips: n_flips, mean_hamming_distance }
+}
@@ -0,0 +1,75 @@
+use std::io::{self, IsTerminal};
+
+use indicatif::{ProgressBar, ProgressStyle};
+use tracing::debug;
+
+// Shows a progress bar with ETA when stderr is a terminal, and falls back to
+// a debug-level log line every 1000 generations otherwise. The bar is finished on drop,
+// so runs that stabilize early still leave it completed.
+pub struct ProgressReporter {
+    bar: Option<ProgressBar>,
+    log: bool,
+}
+
+impl ProgressReporter {
+    pub fn new(total: u64) -> Self {
+        if !io::stderr().is_terminal() {
+            return Self::logging();
+        }
+        let bar = ProgressBar::new(total);
+        bar.set_style(
+            ProgressStyle::with_template("[generation {pos}/{len}] pop={msg} {per_sec} elapsed {elapsed} ETA {eta}")
+                .expect("valid progress template"),
+        );
+        ProgressReporter { bar: Some(bar), log: false }
+    }
+
+    // Only the log line every 1000 generations.
+    pub fn logging() -> Self {
+        ProgressReporter { bar: None, log: true }
+    }
+
+    // Reports nothing.
+    pub fn hidden() -> Self {
+        ProgressReporter { bar: None, log: false }
+    }
+
+    pub fn update(&mut self, generation: usize, population: usize) {
+        self.report(generation, population, None);
+    }
+
+    // Like `update`, with the `current_stability_score` shown next to the
+    // population.
+    pub fn update_with_stability(&mut self, generation: usize, population: usize, score: f64) {
+        self.report(generation, population, Some(score));
+    }
+
+    fn report(&mut self, generation: usize, population: usize, score: Option<f64>) {
+        match (&self.bar, score) {
+            (Some(bar), score) => {
+                bar.set_position(generation as u64);
+                match score {
+                    Some(score) => bar.set_message(format!("{} stability={:.2}", population, score)),
+                    None => bar.set_message(population.to_string()),
+                }
+            }
+            (None, _) if !self.log || !generation.is_multiple_of(1000) => {}
+            (None, Some(score)) => debug!("Generation {}: Population = {}, stability {:.2}", generation, population, score),
+            (None, None) => debug!("Generation {}: Population = {}", generation, population),
+        }
+    }
+
+    pub fn finish(&mut self) {
+        if let Some(bar) = self.bar.take() {
+            bar.finish();
+            // The bar leaves the cursor at the end of its line.
+            eprintln!();
+        }
+    }
+}
+
+impl Drop for ProgressReporter {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
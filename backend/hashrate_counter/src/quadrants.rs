@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+use crate::{GameOfLife, Point};
+
+// Live cells in Q1 (x >= 0, y >= 0), Q2 (x < 0, y >= 0), Q3 (x < 0, y < 0)
+// and Q4 (x >= 0, y < 0). Cells on an axis count towards the non-negative side.
+pub fn quadrant_counts(cells: &HashSet<Point>) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for cell in cells {
+        let quadrant = match (cell.x >= 0, cell.y >= 0) {
+            (true, true) => 0,
+            (false, true) => 1,
+            (false, false) => 2,
+            (true, false) => 3,
+        };
+        counts[quadrant] += 1;
+    }
+    counts
+}
+
+impl GameOfLife {
+    pub fn track_quadrants(&self) -> bool {
+        self.track_quadrants
+    }
+
+    pub fn set_track_quadrants(&mut self, enabled: bool) {
+        self.track_quadrants = enabled;
+    }
+
+    pub(crate) fn record_quadrants(&mut self, generation: usize) {
+        if self.track_quadrants {
+            self.quadrant_series.insert(generation, quadrant_counts(&self.live_cells));
+        }
+    }
+}
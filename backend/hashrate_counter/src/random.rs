@@ -0,0 +1,85 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand::rngs::Xoshiro256PlusPlus;
+use rand::{RngExt, SeedableRng};
+
+use crate::{GameOfLife, Point, PowercelaError};
+
+// The parameters behind `--random width=50,height=50,density=0.35,seed=42`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RandomSpec {
+    pub width: u32,
+    pub height: u32,
+    pub density: f64,
+    pub seed: u64,
+}
+
+impl Default for RandomSpec {
+    fn default() -> Self {
+        RandomSpec { width: 50, height: 50, density: 0.5, seed: 0 }
+    }
+}
+
+impl FromStr for RandomSpec {
+    type Err = PowercelaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut spec = RandomSpec::default();
+        for part in s.split(',') {
+            let invalid = || PowercelaError::ParseError(format!("invalid random pattern option {:?}", part));
+            let (key, value) = part.split_once('=').ok_or_else(invalid)?;
+            match key {
+                "width" => spec.width = value.parse().map_err(|_| invalid())?,
+                "height" => spec.height = value.parse().map_err(|_| invalid())?,
+                "density" => spec.density = value.parse().map_err(|_| invalid())?,
+                "seed" => spec.seed = value.parse().map_err(|_| invalid())?,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(spec)
+    }
+}
+
+impl fmt::Display for RandomSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "random_{}x{}_{}_{}", self.width, self.height, self.density, self.seed)
+    }
+}
+
+impl GameOfLife {
+    pub fn random_pattern(width: u32, height: u32, density: f64, seed: u64) -> Result<GameOfLife, PowercelaError> {
+        let mut game = GameOfLife::new();
+        game.load_random(&RandomSpec { width, height, density, seed })?;
+        Ok(game)
+    }
+
+    // Fills a width x height box, centered like the other loaders (or at the
+    // origin on a torus). The generator is Xoshiro256++ rather than `SmallRng`,
+    // which is the same algorithm on 64-bit targets but a different one on
+    // 32-bit, so a seed gives the same cells everywhere.
+    pub fn load_random(&mut self, spec: &RandomSpec) -> Result<(), PowercelaError> {
+        if !(0.0..=1.0).contains(&spec.density) {
+            return Err(PowercelaError::InvalidPattern(format!(
+                "density must be between 0.0 and 1.0, got {}",
+                spec.density
+            )));
+        }
+
+        let (start_x, start_y) = if self.topology().is_infinite() {
+            (-(spec.width as i64 / 2), -(spec.height as i64 / 2))
+        } else {
+            (0, 0)
+        };
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(spec.seed);
+        for y in 0..spec.height as i64 {
+            for x in 0..spec.width as i64 {
+                if rng.random_bool(spec.density) {
+                    let cell = self.topology().wrap(Point::new(start_x + x, start_y + y));
+                    self.live_cells.insert(cell);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{BoundingBox, GameOfLife, Point};
+
+impl GameOfLife {
+    // The bounding box as `height` rows of `width` cells, `true` where a cell
+    // is alive: `grid[y - min_y][x - min_x]`. `None` without live cells. The
+    // grid has one entry per cell of the box, so check `bounding_box` first
+    // for patterns that have spread far apart.
+    pub fn rasterize(&self) -> Option<(Vec<Vec<bool>>, BoundingBox)> {
+        let bbox = self.bounding_box()?;
+        let mut grid = vec![vec![false; bbox.width() as usize]; bbox.height() as usize];
+        for cell in &self.live_cells {
+            grid[(cell.y - bbox.min_y) as usize][(cell.x - bbox.min_x) as usize] = true;
+        }
+        Some((grid, bbox))
+    }
+
+    // Adds a cell for every `true` in `grid`, with `grid[0][0]` at `origin`,
+    // wrapped onto the torus if there is one. Rows may differ in length.
+    pub fn load_raster(&mut self, grid: &[&[bool]], origin: Point) {
+        let topology = self.topology();
+        for (y, row) in grid.iter().enumerate() {
+            for (x, _) in row.iter().enumerate().filter(|&(_, &alive)| alive) {
+                let cell = Point::new(origin.x.wrapping_add(x as i64), origin.y.wrapping_add(y as i64));
+                self.live_cells.insert(topology.wrap(cell));
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use image::error::{LimitError, LimitErrorKind};
+use image::{ImageError, ImageResult, Rgba, RgbaImage};
+
+use crate::{BoundingBox, Point};
+
+// Largest PNG side, in pixels, that `render_png` will produce.
+pub const MAX_PNG_SIDE: u64 = 4096;
+
+// The pixels per cell the binary's exports accept. The functions here take
+// any size.
+pub const CELL_SIZES: [u32; 6] = [1, 2, 4, 8, 16, 32];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgStyle {
+    pub fill: String,
+    pub background: String,
+    pub stroke: String,
+    pub stroke_width: f64,
+}
+
+impl Default for SvgStyle {
+    fn default() -> Self {
+        SvgStyle {
+            fill: "#000000".to_string(),
+            background: "#ffffff".to_string(),
+            stroke: "none".to_string(),
+            stroke_width: 0.0,
+        }
+    }
+}
+
+pub fn render_svg(cells: &HashSet<Point>, cell_size: u32, path: &Path) -> io::Result<()> {
+    render_svg_with(cells, cell_size, &SvgStyle::default(), path)
+}
+
+// The viewport is the bounding box scaled by `cell_size`, with its minimum
+// corner moved to (0, 0) so no rect lands at a negative position.
+pub fn render_svg_with(cells: &HashSet<Point>, cell_size: u32, style: &SvgStyle, path: &Path) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    let size = cell_size as u64;
+    let (bbox, width, height) = match BoundingBox::from_cells(cells) {
+        Some(bbox) => (Some(bbox), bbox.width().saturating_mul(size), bbox.height().saturating_mul(size)),
+        None => (None, 0, 0),
+    };
+
+    writeln!(
+        writer,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = width,
+        h = height
+    )?;
+    writeln!(writer, r#"<rect width="100%" height="100%" fill="{}"/>"#, escape(&style.background))?;
+
+    if let Some(bbox) = bbox {
+        let mut sorted: Vec<&Point> = cells.iter().collect();
+        sorted.sort_by_key(|cell| (cell.y, cell.x));
+        writeln!(
+            writer,
+            r#"<g fill="{}" stroke="{}" stroke-width="{}">"#,
+            escape(&style.fill),
+            escape(&style.stroke),
+            style.stroke_width
+        )?;
+        for cell in sorted {
+            let x = ((cell.x as i128 - bbox.min_x as i128) as u64).saturating_mul(size);
+            let y = ((cell.y as i128 - bbox.min_y as i128) as u64).saturating_mul(size);
+            writeln!(writer, r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#, x, y, size, size)?;
+        }
+        writeln!(writer, "</g>")?;
+    }
+
+    writeln!(writer, "</svg>")?;
+    writer.flush()
+}
+
+// Black cells on a white background. Images wider or taller than
+// MAX_PNG_SIDE pixels are rejected instead of allocating gigabytes.
+pub fn render_png(cells: &HashSet<Point>, cell_size: u32, path: &Path) -> ImageResult<()> {
+    let size = cell_size as u64;
+    let (width, height) = match BoundingBox::from_cells(cells) {
+        Some(bbox) => (bbox.width().saturating_mul(size), bbox.height().saturating_mul(size)),
+        None => (size, size),
+    };
+    if width > MAX_PNG_SIDE || height > MAX_PNG_SIDE || width == 0 || height == 0 {
+        return Err(ImageError::Limits(LimitError::from_kind(LimitErrorKind::DimensionError)));
+    }
+
+    let mut image = RgbaImage::from_pixel(width as u32, height as u32, Rgba([255, 255, 255, 255]));
+    if let Some(bbox) = BoundingBox::from_cells(cells) {
+        for cell in cells {
+            fill_cell(&mut image, (cell.x - bbox.min_x) as u32, (cell.y - bbox.min_y) as u32, cell_size);
+        }
+    }
+    image.save(path)
+}
+
+// One row of pixels per row of cells, top to bottom, such as the space-time
+// diagram from `simulate_1d`. Unlike `render_png` the whole grid is drawn,
+// dead cells included.
+pub fn render_rows_png(rows: &[Vec<bool>], cell_size: u32, path: &Path) -> ImageResult<()> {
+    let size = cell_size as u64;
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0) as u64;
+    let (width, height) = (columns.saturating_mul(size), (rows.len() as u64).saturating_mul(size));
+    if width > MAX_PNG_SIDE || height > MAX_PNG_SIDE || width == 0 || height == 0 {
+        return Err(ImageError::Limits(LimitError::from_kind(LimitErrorKind::DimensionError)));
+    }
+
+    let mut image = RgbaImage::from_pixel(width as u32, height as u32, Rgba([255, 255, 255, 255]));
+    for (y, row) in rows.iter().enumerate() {
+        for (x, _) in row.iter().enumerate().filter(|&(_, &alive)| alive) {
+            fill_cell(&mut image, x as u32, y as u32, cell_size);
+        }
+    }
+    image.save(path)
+}
+
+// Each cell of the heatmap's bounding box in a color from blue, for cells
+// alive once, to red, for the most often alive, on white for cells never
+// alive. Cells are drawn up to 8 pixels wide while the image stays within
+// MAX_PNG_SIDE.
+pub fn write_heatmap_png(heatmap: &HashMap<Point, usize>, path: &Path) -> ImageResult<()> {
+    let bbox = BoundingBox::from_cells(heatmap.keys());
+    let (columns, rows) = bbox.as_ref().map_or((1, 1), |bbox| (bbox.width(), bbox.height()));
+    let cell_size = (MAX_PNG_SIDE / columns.max(rows).max(1)).min(8);
+    if cell_size == 0 {
+        return Err(ImageError::Limits(LimitError::from_kind(LimitErrorKind::DimensionError)));
+    }
+
+    let cell_size = cell_size as u32;
+    let mut image = RgbaImage::from_pixel(columns as u32 * cell_size, rows as u32 * cell_size, Rgba([255, 255, 255, 255]));
+    if let Some(bbox) = bbox {
+        let max = heatmap.values().copied().max().unwrap_or(1);
+        for (cell, &count) in heatmap {
+            let heat = if max > 1 { (count - 1) as f64 / (max - 1) as f64 } else { 1.0 };
+            let color = Rgba([(255.0 * heat).round() as u8, 0, (255.0 * (1.0 - heat)).round() as u8, 255]);
+            fill_cell_with(&mut image, (cell.x - bbox.min_x) as u32, (cell.y - bbox.min_y) as u32, cell_size, color);
+        }
+    }
+    image.save(path)
+}
+
+fn fill_cell(image: &mut RgbaImage, x: u32, y: u32, cell_size: u32) {
+    fill_cell_with(image, x, y, cell_size, Rgba([0, 0, 0, 255]));
+}
+
+fn fill_cell_with(image: &mut RgbaImage, x: u32, y: u32, cell_size: u32, color: Rgba<u8>) {
+    for dy in 0..cell_size {
+        for dx in 0..cell_size {
+            image.put_pixel(x * cell_size + dx, y * cell_size + dy, color);
+        }
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{GameOfLife, Point, SimulationResult};
+
+// One generation of a saved result.
+#[derive(Clone, Debug)]
+pub struct ReplayFrame {
+    pub generation: usize,
+    pub population: usize,
+    // The live cells, when the result has a snapshot of this generation.
+    pub cells: Option<HashSet<Point>>,
+}
+
+impl SimulationResult {
+    // Every recorded generation in order.
+    pub fn replay(&self) -> impl Iterator<Item = ReplayFrame> + '_ {
+        self.generations.iter().map(|(&generation, &population)| ReplayFrame {
+            generation,
+            population,
+            cells: self
+                .snapshots
+                .as_ref()
+                .and_then(|snapshots| snapshots.get(&generation))
+                .map(|cells| cells.iter().map(|&[x, y]| Point::new(x, y)).collect()),
+        })
+    }
+}
+
+// The same header as `GameOfLife`'s, followed by the grid when the frame has
+// cells.
+impl fmt::Display for ReplayFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cells {
+            Some(cells) => {
+                let game = GameOfLife { live_cells: cells.clone(), generation: self.generation, ..GameOfLife::new() };
+                write!(f, "{}", game)
+            }
+            None => write!(f, "Generation {}, population {}", self.generation, self.population),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::extract::rejection::{JsonRejection, QueryRejection};
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+use tokio::runtime::Runtime;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::mpsc;
+use tokio::task;
+use tracing::info;
+
+use crate::{limits, GameOfLife, PatternLibrary, PowercelaError, SimulationResult, StabilizationKind};
+
+pub use crate::limits::{MAX_CELLS, MAX_ITERATIONS};
+
+// Generation messages a watcher may fall behind by before it skips ahead.
+const BROADCAST_CAPACITY: usize = 1024;
+
+// Generation messages the client that started a simulation may have queued
+// before the simulation waits for it.
+const OWNER_BUFFER: usize = 64;
+
+// The body of `POST /simulate`, or the query of `GET /ws/simulate`. `pattern`
+// is a built-in pattern name, or a flat 0/1 string when `splitAmount` is
+// given, as on the command line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateRequest {
+    pub pattern: String,
+    pub split_amount: Option<usize>,
+    pub iterations: usize,
+}
+
+// The WebSocket simulations still running, by request. Clients asking for the
+// same one share its messages.
+#[derive(Clone, Default)]
+struct Simulations(Arc<Mutex<HashMap<SimulateRequest, broadcast::Sender<String>>>>);
+
+type ApiError = (StatusCode, Json<Value>);
+
+fn bad_request(message: impl ToString) -> ApiError {
+    (StatusCode::BAD_REQUEST, Json(json!({ "error": message.to_string() })))
+}
+
+// `GET /health`, `GET /patterns`, `POST /simulate` and `GET /ws/simulate`.
+pub fn router() -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/patterns", get(patterns))
+        .route("/simulate", post(simulate))
+        .route("/ws/simulate", get(simulate_ws))
+        .with_state(Simulations::default())
+}
+
+// Serves `router` on 127.0.0.1:`port` until the process is stopped.
+pub fn serve(port: u16) -> Result<(), PowercelaError> {
+    let runtime = Runtime::new()?;
+    runtime.block_on(async {
+        let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port))).await?;
+        info!("Listening on http://{}", listener.local_addr()?);
+        axum::serve(listener, router()).await?;
+        Ok(())
+    })
+}
+
+async fn health() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+async fn patterns() -> Json<Value> {
+    let patterns = PatternLibrary::all()
+        .iter()
+        .map(|pattern| json!({ "name": pattern.name, "width": pattern.width, "height": pattern.height() }));
+    Json(Value::Array(patterns.collect()))
+}
+
+// Every request simulates its own game on the blocking thread pool.
+async fn simulate(request: Result<Json<SimulateRequest>, JsonRejection>) -> Result<Json<SimulationResult>, ApiError> {
+    let Json(request) = request.map_err(|rejection| bad_request(rejection.body_text()))?;
+    let mut game = build_game(&request).map_err(bad_request)?;
+    let result = task::spawn_blocking(move || {
+        let fingerprint = game.pattern_fingerprint();
+        let stabilization_kind = game.simulate(request.iterations, None);
+        SimulationResult::from_game(game, stabilization_kind, fingerprint)
+    });
+    let result = result.await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, Json(json!({ "error": e.to_string() }))))?;
+    Ok(Json(result))
+}
+
+fn build_game(request: &SimulateRequest) -> Result<GameOfLife, PowercelaError> {
+    limits::build_game(&request.pattern, request.split_amount, None, request.iterations)
+}
+
+// Streams `{"gen", "pop"}` for every generation, then
+// `{"done": true, "stabilizationKind": ...}`, and closes. The simulation runs
+// at the pace of the client that started it, which gets every message. A
+// client asking for a simulation that is already running joins it from the
+// current generation as a watcher; a watcher that falls too far behind gets
+// `{"lagged": <skipped messages>}` and continues from the oldest one kept.
+// `{"stop": true}` from the client that started it ends it for everyone; from
+// a watcher it only closes that watcher's socket.
+async fn simulate_ws(
+    upgrade: WebSocketUpgrade,
+    State(simulations): State<Simulations>,
+    request: Result<Query<SimulateRequest>, QueryRejection>,
+) -> Result<Response, ApiError> {
+    let Query(request) = request.map_err(|rejection| bad_request(rejection.body_text()))?;
+    let game = build_game(&request).map_err(bad_request)?;
+    let feed = simulations.watch(request, game);
+    Ok(upgrade.on_upgrade(move |socket| forward(socket, feed)))
+}
+
+// Where a WebSocket client's messages come from.
+enum Feed {
+    // The client that started the simulation, and its stop flag.
+    Owner(mpsc::Receiver<String>, Arc<AtomicBool>),
+    Watcher(broadcast::Receiver<String>),
+}
+
+impl Feed {
+    // The next message, or `None` once the simulation has ended.
+    async fn next(&mut self) -> Option<String> {
+        match self {
+            Feed::Owner(receiver, _) => receiver.recv().await,
+            Feed::Watcher(receiver) => match receiver.recv().await {
+                Ok(text) => Some(text),
+                Err(RecvError::Lagged(skipped)) => Some(json!({ "lagged": skipped }).to_string()),
+                Err(RecvError::Closed) => None,
+            },
+        }
+    }
+
+    fn stop(&self) {
+        if let Feed::Owner(_, stop) = self {
+            stop.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Simulations {
+    // Subscribes to the running simulation for `request`, or starts `game`.
+    fn watch(&self, request: SimulateRequest, game: GameOfLife) -> Feed {
+        let mut running = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(sender) = running.get(&request) {
+            return Feed::Watcher(sender.subscribe());
+        }
+        let (sender, _) = broadcast::channel(BROADCAST_CAPACITY);
+        running.insert(request.clone(), sender.clone());
+        let (owner, receiver) = mpsc::channel(OWNER_BUFFER);
+        let stop = Arc::new(AtomicBool::new(false));
+        let simulations = self.clone();
+        let stopped = Arc::clone(&stop);
+        task::spawn_blocking(move || simulations.run(request, game, sender, owner, &stopped));
+        Feed::Owner(receiver, stop)
+    }
+
+    // Waits for the owner at every generation while it is connected. Ends
+    // early once stopped or when every client has gone.
+    fn run(
+        &self,
+        request: SimulateRequest,
+        mut game: GameOfLife,
+        sender: broadcast::Sender<String>,
+        owner: mpsc::Sender<String>,
+        stop: &AtomicBool,
+    ) {
+        let mut owner = Some(owner);
+        let outcome = game.simulate_observed(request.iterations, |generation, cells| {
+            if stop.load(Ordering::Relaxed) {
+                return Err(());
+            }
+            let message = json!({ "gen": generation, "pop": cells.len() }).to_string();
+            if owner.as_ref().is_some_and(|owner| owner.blocking_send(message.clone()).is_err()) {
+                owner = None;
+            }
+            let watched = sender.send(message).is_ok();
+            if owner.is_none() && !watched {
+                return Err(());
+            }
+            Ok(())
+        });
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).remove(&request);
+        let stabilization_kind = outcome.unwrap_or(StabilizationKind::None);
+        let done = json!({ "done": true, "stabilizationKind": stabilization_kind }).to_string();
+        if let Some(owner) = owner {
+            let _ = owner.blocking_send(done.clone());
+        }
+        let _ = sender.send(done);
+    }
+}
+
+async fn forward(mut socket: WebSocket, mut feed: Feed) {
+    loop {
+        tokio::select! {
+            message = feed.next() => match message {
+                Some(text) => {
+                    if socket.send(Message::Text(text.into())).await.is_err() {
+                        return;
+                    }
+                }
+                None => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) if is_stop(&text) => {
+                    feed.stop();
+                    break;
+                }
+                Some(Ok(Message::Close(_)) | Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    let _ = socket.send(Message::Close(None)).await;
+}
+
+fn is_stop(text: &str) -> bool {
+    serde_json::from_str::<Value>(text).is_ok_and(|message| message["stop"] == true)
+}
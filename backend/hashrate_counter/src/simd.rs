@@ -0,0 +1,47 @@
+use std::collections::{HashMap, HashSet};
+
+use wide::i64x4;
+
+use crate::{GameOfLife, Point, StabilizationKind};
+
+const LANES: usize = 4;
+
+impl GameOfLife {
+    pub fn simulate_simd(&mut self, iterations: usize) -> StabilizationKind {
+        self.run(iterations, 0, Self::next_generation_simd)
+    }
+
+    // Neighbor coordinates for four cells at a time: each offset is added to a
+    // whole lane of x and y coordinates before the results go into the map.
+    fn next_generation_simd(&self) -> HashSet<Point> {
+        let cells: Vec<Point> = self.live_cells.iter().copied().collect();
+        let mut neighbor_counts = HashMap::with_capacity(cells.len() * self.neighbor_offsets.len());
+
+        for chunk in cells.chunks(LANES) {
+            let mut xs = [0; LANES];
+            let mut ys = [0; LANES];
+            for (lane, cell) in chunk.iter().enumerate() {
+                xs[lane] = cell.x;
+                ys[lane] = cell.y;
+            }
+            let (xs, ys) = (i64x4::new(xs), i64x4::new(ys));
+
+            for &(dx, dy) in &self.neighbor_offsets {
+                let nx = (xs + i64x4::splat(dx)).to_array();
+                let ny = (ys + i64x4::splat(dy)).to_array();
+                for lane in 0..chunk.len() {
+                    let neighbor = self.topology.wrap(Point::new(nx[lane], ny[lane]));
+                    *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut new_live_cells = self.isolated_survivors(&neighbor_counts);
+        for (cell, count) in neighbor_counts {
+            if self.rule.next_state(self.live_cells.contains(&cell), count) {
+                new_live_cells.insert(cell);
+            }
+        }
+        new_live_cells
+    }
+}
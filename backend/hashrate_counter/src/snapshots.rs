@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+
+use crate::GameOfLife;
+
+impl GameOfLife {
+    pub fn snapshot_at(&self) -> &HashSet<usize> {
+        &self.snapshot_at
+    }
+
+    // Generations whose live cells are copied into `snapshots` as the run reaches them.
+    pub fn set_snapshot_at(&mut self, generations: HashSet<usize>) {
+        self.snapshot_at = generations;
+    }
+
+    pub(crate) fn record_snapshot(&mut self, generation: usize) {
+        if !self.snapshot_at.contains(&generation) {
+            return;
+        }
+        let mut cells: Vec<[i64; 2]> = self.live_cells.iter().map(|cell| [cell.x, cell.y]).collect();
+        cells.sort_unstable_by_key(|&[x, y]| (y, x));
+        self.snapshots.insert(generation, cells);
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{BoundingBox, GameOfLife, Point, Rule};
+
+const LEAF_CAPACITY: usize = 64;
+const MAX_LEAF_AREA: u64 = 4096;
+const MIN_REGION_SIDE: u64 = 16;
+
+// A loose quadtree over the live cells. Each leaf owns a rectangular region
+// of the plane; regions are disjoint, so a leaf's cells are the only live
+// cells inside it.
+pub struct SpatialIndex {
+    leaves: Vec<Leaf>,
+}
+
+struct Leaf {
+    region: BoundingBox,
+    cells: Vec<Point>,
+}
+
+impl SpatialIndex {
+    pub fn build(cells: &HashSet<Point>) -> Self {
+        let mut leaves = Vec::new();
+        if let Some(region) = BoundingBox::from_cells(cells) {
+            split(region, cells.iter().copied().collect(), &mut leaves);
+        }
+        SpatialIndex { leaves }
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaves.len()
+    }
+
+    // Cells whose whole 3x3 neighborhood lies inside one leaf are decided from
+    // a small dense grid; only the strips along leaf borders go through a
+    // shared hash map.
+    pub fn next_generation(&self, rule: &Rule) -> HashSet<Point> {
+        let mut next = HashSet::new();
+        let mut border_counts: HashMap<Point, (bool, u8)> = HashMap::new();
+
+        for leaf in &self.leaves {
+            let Some(bbox) = BoundingBox::from_cells(&leaf.cells) else {
+                continue;
+            };
+            let (origin_x, origin_y) = (bbox.min_x - 1, bbox.min_y - 1);
+            let width = bbox.width() as usize + 2;
+            let height = bbox.height() as usize + 2;
+            let mut counts = vec![0u8; width * height];
+            let mut alive = vec![false; width * height];
+
+            for cell in &leaf.cells {
+                let (lx, ly) = ((cell.x - origin_x) as usize, (cell.y - origin_y) as usize);
+                alive[ly * width + lx] = true;
+                for dy in 0..3 {
+                    for dx in 0..3 {
+                        if (dx, dy) != (1, 1) {
+                            counts[(ly + dy - 1) * width + (lx + dx - 1)] += 1;
+                        }
+                    }
+                }
+            }
+
+            let region = leaf.region;
+            for ly in 0..height {
+                for lx in 0..width {
+                    let index = ly * width + lx;
+                    let (count, is_alive) = (counts[index], alive[index]);
+                    if count == 0 && !is_alive {
+                        continue;
+                    }
+                    let point = Point::new(origin_x + lx as i64, origin_y + ly as i64);
+                    let interior = point.x > region.min_x
+                        && point.x < region.max_x
+                        && point.y > region.min_y
+                        && point.y < region.max_y;
+                    if interior {
+                        if rule.next_state(is_alive, count) {
+                            next.insert(point);
+                        }
+                    } else {
+                        let entry = border_counts.entry(point).or_insert((false, 0));
+                        entry.0 |= is_alive;
+                        entry.1 += count;
+                    }
+                }
+            }
+        }
+
+        for (point, (is_alive, count)) in border_counts {
+            if rule.next_state(is_alive, count) {
+                next.insert(point);
+            }
+        }
+        next
+    }
+}
+
+// The dense leaf grids pad each cell by one on every side; patterns touching
+// the i64 limits take the wrapping hash map path instead.
+pub(crate) fn fits(cells: &HashSet<Point>) -> bool {
+    BoundingBox::from_cells(cells).is_none_or(|bbox| {
+        bbox.min_x > i64::MIN && bbox.max_x < i64::MAX && bbox.min_y > i64::MIN && bbox.max_y < i64::MAX
+    })
+}
+
+fn split(region: BoundingBox, cells: Vec<Point>, leaves: &mut Vec<Leaf>) {
+    let small_region = region.width() <= MIN_REGION_SIDE && region.height() <= MIN_REGION_SIDE;
+    let compact = cells.len() <= LEAF_CAPACITY
+        && BoundingBox::from_cells(&cells).is_some_and(|bbox| bbox.area() <= MAX_LEAF_AREA);
+    if small_region || compact {
+        leaves.push(Leaf { region, cells });
+        return;
+    }
+
+    let center = region.center();
+    let quadrants = [
+        BoundingBox { min_x: region.min_x, max_x: center.x, min_y: region.min_y, max_y: center.y },
+        BoundingBox { min_x: center.x + 1, max_x: region.max_x, min_y: region.min_y, max_y: center.y },
+        BoundingBox { min_x: region.min_x, max_x: center.x, min_y: center.y + 1, max_y: region.max_y },
+        BoundingBox { min_x: center.x + 1, max_x: region.max_x, min_y: center.y + 1, max_y: region.max_y },
+    ];
+    let mut buckets: [Vec<Point>; 4] = Default::default();
+    for cell in cells {
+        let index = (cell.x > center.x) as usize + 2 * (cell.y > center.y) as usize;
+        buckets[index].push(cell);
+    }
+    for (quadrant, bucket) in quadrants.into_iter().zip(buckets) {
+        if !bucket.is_empty() {
+            split(quadrant, bucket, leaves);
+        }
+    }
+}
+
+impl GameOfLife {
+    pub fn with_spatial_index(mut self, enabled: bool) -> Self {
+        self.set_spatial_index(enabled);
+        self
+    }
+
+    pub fn set_spatial_index(&mut self, enabled: bool) {
+        self.spatial_index = enabled;
+    }
+
+    pub fn spatial_index(&self) -> bool {
+        self.spatial_index
+    }
+}
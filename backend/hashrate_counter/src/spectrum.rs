@@ -0,0 +1,44 @@
+use std::f64::consts::PI;
+
+use rustfft::num_complex::Complex;
+use rustfft::FftPlanner;
+
+use crate::SimulationResult;
+
+// Magnitude spectrum of the population series: the mean is removed, a Hann
+// window applied and the series zero-padded to the next power of two `n`.
+// Bin k of the `n / 2 + 1` returned is the frequency k / n per generation.
+pub fn population_spectrum(history: &[usize]) -> Vec<f64> {
+    if history.is_empty() {
+        return Vec::new();
+    }
+    let len = history.len();
+    let n = len.next_power_of_two();
+    let mean = history.iter().sum::<usize>() as f64 / len as f64;
+    let mut buffer: Vec<Complex<f64>> = history
+        .iter()
+        .enumerate()
+        .map(|(i, &population)| {
+            let window = if len == 1 { 1.0 } else { 0.5 - 0.5 * (2.0 * PI * i as f64 / (len - 1) as f64).cos() };
+            Complex::new((population as f64 - mean) * window, 0.0)
+        })
+        .collect();
+    buffer.resize(n, Complex::new(0.0, 0.0));
+    FftPlanner::new().plan_fft_forward(n).process(&mut buffer);
+    buffer[..=n / 2].iter().map(|bin| bin.norm()).collect()
+}
+
+// The period of the strongest non-DC bin, in generations. `None` for a
+// constant or too short series.
+pub fn dominant_period(history: &[usize]) -> Option<f64> {
+    let spectrum = population_spectrum(history);
+    let (bin, &magnitude) = spectrum.iter().enumerate().skip(1).max_by(|a, b| a.1.total_cmp(b.1))?;
+    (magnitude > 0.0).then(|| history.len().next_power_of_two() as f64 / bin as f64)
+}
+
+impl SimulationResult {
+    pub fn include_spectrum(&mut self) {
+        let history: Vec<usize> = self.generations.values().copied().collect();
+        self.dominant_period_estimate = dominant_period(&history);
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Point, SimulationResult};
+
+pub const DEFAULT_DELTA_WINDOW: usize = 10;
+pub const DEFAULT_VARIANCE_WINDOW: usize = 100;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PopulationStats {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub variance: f64,
+}
+
+impl PopulationStats {
+    // Single pass using Welford's update; the variance is the population
+    // variance. An empty map gives all zeros.
+    pub fn from_generations(data: &BTreeMap<usize, usize>) -> Self {
+        let mut stats = PopulationStats { min: usize::MAX, ..Self::default() };
+        let mut sum_of_squares = 0.0;
+        let mut count = 0.0;
+        for &population in data.values() {
+            stats.min = stats.min.min(population);
+            stats.max = stats.max.max(population);
+            count += 1.0;
+            let delta = population as f64 - stats.mean;
+            stats.mean += delta / count;
+            sum_of_squares += delta * (population as f64 - stats.mean);
+        }
+        if count == 0.0 {
+            return Self::default();
+        }
+        stats.variance = sum_of_squares / count;
+        stats
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgeStats {
+    pub max: usize,
+    pub mean: f64,
+    // Number of live cells of each age.
+    pub distribution: BTreeMap<usize, usize>,
+}
+
+impl AgeStats {
+    pub fn from_ages(ages: &HashMap<Point, usize>) -> Self {
+        let mut stats = AgeStats::default();
+        for &age in ages.values() {
+            stats.max = stats.max.max(age);
+            *stats.distribution.entry(age).or_insert(0) += 1;
+        }
+        if !ages.is_empty() {
+            stats.mean = ages.values().sum::<usize>() as f64 / ages.len() as f64;
+        }
+        stats
+    }
+}
+
+// Population variance of the last `window` values of `history`, or of all of
+// them when there are fewer; 0 for an empty history.
+pub fn rolling_variance(history: &[usize], window: usize) -> f64 {
+    let recent = &history[history.len().saturating_sub(window)..];
+    if recent.is_empty() {
+        return 0.0;
+    }
+    let n = recent.len() as f64;
+    let mean = recent.iter().sum::<usize>() as f64 / n;
+    recent.iter().map(|&population| (population as f64 - mean).powi(2)).sum::<f64>() / n
+}
+
+// The longest run of recorded generations with the same population, as its
+// length and first generation. The earliest wins a tie; no generations give
+// (0, 0).
+pub fn longest_stability_streak(data: &BTreeMap<usize, usize>) -> (usize, usize) {
+    let mut longest = (0, 0);
+    let mut current = (0, 0);
+    let mut previous = None;
+    for (&generation, &population) in data {
+        current = if previous == Some(population) { (current.0 + 1, current.1) } else { (1, generation) };
+        previous = Some(population);
+        if current.0 > longest.0 {
+            longest = current;
+        }
+    }
+    longest
+}
+
+// Change in population since the previous recorded generation, keyed by the
+// later generation, so the first generation has no entry.
+pub fn population_deltas(data: &BTreeMap<usize, usize>) -> BTreeMap<usize, i64> {
+    data.iter()
+        .zip(data.iter().skip(1))
+        .map(|((_, &before), (&generation, &after))| (generation, after as i64 - before as i64))
+        .collect()
+}
+
+// Mean of the last `window` deltas at each generation; the first few average
+// over however many deltas there are so far.
+pub fn rolling_average(deltas: &BTreeMap<usize, i64>, window: usize) -> BTreeMap<usize, f64> {
+    let window = window.max(1);
+    let values: Vec<i64> = deltas.values().copied().collect();
+    let mut sum = 0;
+    deltas
+        .keys()
+        .enumerate()
+        .map(|(i, &generation)| {
+            sum += values[i];
+            if i >= window {
+                sum -= values[i - window];
+            }
+            (generation, sum as f64 / (i + 1).min(window) as f64)
+        })
+        .collect()
+}
+
+// Normalized autocorrelation r[k] = Cov(X_t, X_t+k) / Var(X) for k = 0..=max_lag,
+// with the covariance summed over the pairs that exist and divided by the full
+// length, which keeps every r[k] within [-1, 1]. A constant series only
+// correlates at lag 0.
+pub fn population_autocorrelation(history: &[usize], max_lag: usize) -> Vec<f64> {
+    let n = history.len() as f64;
+    let mean = history.iter().sum::<usize>() as f64 / n;
+    let centered: Vec<f64> = history.iter().map(|&population| population as f64 - mean).collect();
+    let variance = centered.iter().map(|x| x * x).sum::<f64>() / n;
+    (0..=max_lag)
+        .map(|lag| {
+            if lag == 0 {
+                1.0
+            } else if variance == 0.0 || lag >= centered.len() {
+                0.0
+            } else {
+                let covariance = centered.iter().zip(&centered[lag..]).map(|(a, b)| a * b).sum::<f64>() / n;
+                covariance / variance
+            }
+        })
+        .collect()
+}
+
+impl SimulationResult {
+    pub fn include_autocorrelation(&mut self, max_lag: usize) {
+        let history: Vec<usize> = self.generations.values().copied().collect();
+        self.autocorrelation = Some(population_autocorrelation(&history, max_lag));
+    }
+
+    pub fn include_deltas(&mut self, window: usize) {
+        let deltas = population_deltas(&self.generations);
+        self.rolling_avg_growth = Some(rolling_average(&deltas, window));
+        let mut generation_deltas = deltas.clone();
+        if let Some((&first, &population)) = self.generations.first_key_value() {
+            generation_deltas.insert(first, population as i64);
+        }
+        self.generation_deltas = Some(generation_deltas);
+        self.deltas = Some(deltas);
+    }
+}
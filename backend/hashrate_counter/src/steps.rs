@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+use crate::{GameOfLife, Point};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenerationSnapshot {
+    pub generation: usize,
+    pub live_cells: HashSet<Point>,
+}
+
+// Returned by `GameOfLife::steps`.
+pub struct Steps<'a> {
+    game: &'a mut GameOfLife,
+}
+
+impl Iterator for Steps<'_> {
+    type Item = GenerationSnapshot;
+
+    fn next(&mut self) -> Option<GenerationSnapshot> {
+        if self.game.live_cells.is_empty() {
+            return None;
+        }
+        let generation = self.game.generation + 1;
+        self.game.step_generation(generation, GameOfLife::next_generation);
+        self.game.generation_data.insert(generation, self.game.live_cells.len());
+        Some(GenerationSnapshot { generation, live_cells: self.game.live_cells.clone() })
+    }
+}
+
+impl GameOfLife {
+    // Advances one generation per `next`, with no stability checks. The last
+    // item is the generation that went extinct, if any; after that the
+    // iterator is exhausted.
+    pub fn steps(&mut self) -> Steps<'_> {
+        self.sync_ages();
+        Steps { game: self }
+    }
+}
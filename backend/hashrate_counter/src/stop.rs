@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use tracing::Level;
+
+use crate::{GameOfLife, Point, StabilizationKind};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StopReason {
+    // Any built-in stop other than extinction: a fixed point or oscillator, a
+    // low population variance, or `max_population` or `max_cells` being
+    // exceeded.
+    Stabilized(StabilizationKind),
+    // The first generation `stop_if` returned true for.
+    Predicate(usize),
+    Extinct(usize),
+    Completed,
+}
+
+struct PredicateMet(usize);
+
+impl GameOfLife {
+    // Like `simulate`, but also stops at the first generation, starting with
+    // the current one, for which `stop_if` returns true.
+    pub fn simulate_until<F>(&mut self, max_iterations: usize, mut stop_if: F) -> StopReason
+    where
+        F: FnMut(usize, &HashSet<Point>) -> bool,
+    {
+        let result = self.run_observed(max_iterations, 0, Self::next_generation, |generation, cells| {
+            if stop_if(generation, cells) { Err(PredicateMet(generation)) } else { Ok(()) }
+        });
+        match result {
+            Ok(StabilizationKind::Extinction(generation)) => StopReason::Extinct(generation),
+            Ok(StabilizationKind::None) => StopReason::Completed,
+            Ok(kind) => StopReason::Stabilized(kind),
+            Err(PredicateMet(generation)) => {
+                self.log(Level::INFO, format_args!("Simulation stopped at generation {}: stop condition met", generation));
+                StopReason::Predicate(generation)
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use rayon::prelude::*;
+use tracing::info;
+
+use crate::{GameOfLife, Rule, SimulationResult};
+
+// Runs `pattern` once per rule, each on its own rayon task with the serial
+// stepper, and returns the results in the order of `rules`. Like
+// `batch_simulate`, progress output is off and each rule prints one line.
+pub fn rule_sweep(pattern: GameOfLife, rules: Vec<Rule>, iterations: usize) -> Vec<(Rule, SimulationResult)> {
+    let fingerprint = pattern.pattern_fingerprint();
+    rules
+        .into_par_iter()
+        .map(|rule| {
+            let mut game = pattern.clone();
+            game.set_quiet(true);
+            game.set_rule(rule.clone());
+            let kind = game.simulate(iterations, None);
+            match kind.generation() {
+                Some(generation) => info!("{}: stopped at generation {} ({:?})", rule, generation, kind),
+                None => info!("{}: ran all {} generations", rule, iterations),
+            }
+            (rule, SimulationResult::from_game(game, kind, fingerprint))
+        })
+        .collect()
+}
@@ -0,0 +1,26 @@
+use crate::{GameOfLife, PowercelaError};
+
+impl GameOfLife {
+    // Adds `pattern` to the live cells like `initialize_pattern` and remembers
+    // its cells under `tag`. Loading the same tag again adds to its cells.
+    pub fn load_tagged_pattern(&mut self, tag: &str, pattern: &[&str], start_x: i64, start_y: i64) -> Result<(), PowercelaError> {
+        let mut tagged = GameOfLife::with_topology(self.topology);
+        tagged.initialize_pattern(pattern, start_x, start_y)?;
+        self.live_cells.extend(&tagged.live_cells);
+        self.tagged_cells.entry(tag.to_string()).or_default().extend(tagged.live_cells);
+        Ok(())
+    }
+
+    // A tag's population is the number of live cells on its starting cells,
+    // so it falls as the pattern moves away or is overrun by another one.
+    pub(crate) fn record_tags(&mut self, generation: usize) {
+        for (tag, cells) in &self.tagged_cells {
+            let population = if cells.len() <= self.live_cells.len() {
+                cells.iter().filter(|cell| self.live_cells.contains(cell)).count()
+            } else {
+                self.live_cells.iter().filter(|cell| cells.contains(cell)).count()
+            };
+            self.tagged_populations.entry(tag.clone()).or_default().insert(generation, population);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use tokio::runtime::Runtime;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::PowercelaError;
+
+// Exports `tracing` spans to an OpenTelemetry collector over OTLP/gRPC. The
+// tonic client needs a Tokio runtime, which lives as long as this does.
+// Dropping it flushes the spans that have not been sent yet.
+pub struct Telemetry {
+    provider: SdkTracerProvider,
+    _runtime: Runtime,
+}
+
+impl Telemetry {
+    // `endpoint` is `otlp://host:port` or `http://host:port`, usually port 4317.
+    pub fn otlp(endpoint: &str) -> Result<Self, PowercelaError> {
+        let endpoint = match endpoint.split_once("://") {
+            Some(("otlp" | "http", address)) if !address.is_empty() => format!("http://{}", address),
+            _ => {
+                return Err(PowercelaError::ParseError(format!(
+                    "invalid telemetry endpoint {:?}, expected otlp://<host>:<port>",
+                    endpoint
+                )))
+            }
+        };
+        let runtime = Runtime::new()?;
+        let exporter = runtime.block_on(async {
+            SpanExporter::builder().with_tonic().with_endpoint(endpoint).build()
+        });
+        let exporter = exporter.map_err(|e| PowercelaError::Telemetry(e.to_string()))?;
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(Resource::builder().with_service_name("powercela").build())
+            .build();
+        Ok(Telemetry { provider, _runtime: runtime })
+    }
+
+    pub fn layer<S>(&self) -> OpenTelemetryLayer<S, SdkTracer>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        tracing_opentelemetry::layer().with_tracer(self.provider.tracer("powercela"))
+    }
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        // Nothing is left to report a failed flush to at this point.
+        let _ = self.provider.shutdown();
+    }
+}
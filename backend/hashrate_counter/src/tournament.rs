@@ -0,0 +1,128 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::{GameOfLife, PowercelaError, SimulationResult};
+
+// Which side of a `tournament` a result belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum PatternId {
+    A,
+    B,
+}
+
+// How `tournament_by` picks the winner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WinCriterion {
+    // The pattern that settles at the earlier generation; one that never
+    // settles, or stops by exploding or hitting a cell limit, loses to one
+    // that does.
+    #[default]
+    StabilizesFirst,
+    LargerFinalPopulation,
+    LowerFinalPopulation,
+}
+
+// "stabilizes-first", "larger-final-population" or "lower-final-population".
+impl FromStr for WinCriterion {
+    type Err = PowercelaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('_', "-").as_str() {
+            "stabilizes-first" => Ok(WinCriterion::StabilizesFirst),
+            "larger-final-population" => Ok(WinCriterion::LargerFinalPopulation),
+            "lower-final-population" => Ok(WinCriterion::LowerFinalPopulation),
+            _ => Err(PowercelaError::ParseError(format!(
+                "invalid win criterion {:?}, expected stabilizes-first, larger-final-population or lower-final-population",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for WinCriterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            WinCriterion::StabilizesFirst => "stabilizes-first",
+            WinCriterion::LargerFinalPopulation => "larger-final-population",
+            WinCriterion::LowerFinalPopulation => "lower-final-population",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentResult {
+    // `None` on a tie, or when neither pattern settles under
+    // `StabilizesFirst`.
+    pub winner: Option<PatternId>,
+    pub a_result: SimulationResult,
+    pub b_result: SimulationResult,
+    pub winner_by: WinCriterion,
+}
+
+impl TournamentResult {
+    pub fn result(&self, id: PatternId) -> &SimulationResult {
+        match id {
+            PatternId::A => &self.a_result,
+            PatternId::B => &self.b_result,
+        }
+    }
+
+    // The population of `id`'s last generation.
+    pub fn final_population(&self, id: PatternId) -> usize {
+        final_population(self.result(id))
+    }
+}
+
+// `tournament_by` with `WinCriterion::StabilizesFirst`.
+pub fn tournament(a: GameOfLife, b: GameOfLife, iterations: usize) -> TournamentResult {
+    tournament_by(a, b, iterations, WinCriterion::StabilizesFirst)
+}
+
+// Runs both games for up to `iterations` generations, each on its own rayon
+// task with the serial stepper and progress output off, like
+// `batch_simulate`.
+pub fn tournament_by(a: GameOfLife, b: GameOfLife, iterations: usize, criterion: WinCriterion) -> TournamentResult {
+    let (a_result, b_result) = rayon::join(|| run(a, iterations), || run(b, iterations));
+    let winner = match criterion {
+        // An unsettled run counts as settling after every settled one.
+        WinCriterion::StabilizesFirst => decide(settled_at(&a_result), settled_at(&b_result), |a, b| {
+            a.unwrap_or(usize::MAX).cmp(&b.unwrap_or(usize::MAX)).reverse()
+        }),
+        WinCriterion::LargerFinalPopulation => decide(final_population(&a_result), final_population(&b_result), Ord::cmp),
+        WinCriterion::LowerFinalPopulation => {
+            decide(final_population(&a_result), final_population(&b_result), |a, b| a.cmp(b).reverse())
+        }
+    };
+    TournamentResult { winner, a_result, b_result, winner_by: criterion }
+}
+
+fn run(mut game: GameOfLife, iterations: usize) -> SimulationResult {
+    game.set_quiet(true);
+    let fingerprint = game.pattern_fingerprint();
+    let kind = game.simulate(iterations, None);
+    SimulationResult::from_game(game, kind, fingerprint)
+}
+
+// A wins when `compare` puts it ahead of B.
+fn decide<T>(a: T, b: T, compare: impl Fn(&T, &T) -> Ordering) -> Option<PatternId> {
+    match compare(&a, &b) {
+        Ordering::Greater => Some(PatternId::A),
+        Ordering::Less => Some(PatternId::B),
+        Ordering::Equal => None,
+    }
+}
+
+fn settled_at(result: &SimulationResult) -> Option<usize> {
+    result.stabilization_kind.is_settled().then_some(result.stabilized_at).flatten()
+}
+
+// The population of the last generation simulated.
+fn final_population(result: &SimulationResult) -> usize {
+    result.generations.values().next_back().copied().unwrap_or(0)
+}
@@ -0,0 +1,77 @@
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+use crate::{split_pattern, GameOfLife};
+
+// A `GameOfLife` stepped one generation at a time from JavaScript.
+#[wasm_bindgen]
+pub struct GameOfLifeWasm {
+    game: GameOfLife,
+    unchanged_for: usize,
+    stabilized_at: Option<usize>,
+}
+
+impl Default for GameOfLifeWasm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl GameOfLifeWasm {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        let mut game = GameOfLife::new();
+        game.set_quiet(true);
+        GameOfLifeWasm { game, unchanged_for: 1, stabilized_at: None }
+    }
+
+    // Same input and placement as the CLI's `--pattern`: a string of 0s and
+    // 1s cut into rows of `split` cells, with its rows and columns centered
+    // on the origin.
+    #[wasm_bindgen(js_name = loadFlatPattern)]
+    pub fn load_flat_pattern(&mut self, s: &str, split: usize) -> Result<(), JsError> {
+        let rows = split_pattern(s, split)?;
+        self.game.initialize_pattern_at_origin(&rows.iter().map(String::as_str).collect::<Vec<_>>())?;
+        self.game.generation_data.insert(self.game.generation(), self.game.live_cells.len());
+        Ok(())
+    }
+
+    // Advances one generation and returns the new population.
+    pub fn step(&mut self) -> usize {
+        let previous = self.game.live_cells.len();
+        let generation = self.game.generation() + 1;
+        self.game.step_generation(generation, GameOfLife::next_generation);
+        let population = self.game.live_cells.len();
+        self.game.generation_data.insert(generation, population);
+
+        self.unchanged_for = if population == previous { self.unchanged_for + 1 } else { 1 };
+        let window = self.game.stability_window();
+        if self.stabilized_at.is_none() {
+            if population == 0 {
+                self.stabilized_at = Some(generation);
+            } else if generation >= window && self.unchanged_for >= window {
+                self.stabilized_at = Some(generation - window + 1);
+            }
+        }
+        population
+    }
+
+    // `[x, y]` pairs sorted top to bottom and left to right.
+    #[wasm_bindgen(js_name = getLiveCells)]
+    pub fn get_live_cells(&self) -> Array {
+        let mut cells: Vec<_> = self.game.live_cells.iter().collect();
+        cells.sort_by_key(|cell| (cell.y, cell.x));
+        cells
+            .into_iter()
+            .map(|cell| Array::of2(&JsValue::from_f64(cell.x as f64), &JsValue::from_f64(cell.y as f64)))
+            .collect()
+    }
+
+    // The first generation of the stability window, or of extinction. Only
+    // fixed points are detected, not oscillators.
+    #[wasm_bindgen(js_name = stabilizedAt)]
+    pub fn stabilized_at(&self) -> Option<usize> {
+        self.stabilized_at
+    }
+}
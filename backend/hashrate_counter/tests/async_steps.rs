@@ -0,0 +1,67 @@
+// Stepping on Tokio's blocking pool (async feature).
+#![cfg(feature = "async")]
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Waker};
+
+use powercela::GameOfLife;
+use tokio::runtime::Runtime;
+
+fn glider() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(&["010", "001", "111"], 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn step_async_matches_steps() {
+    // The ages are kept by the game, not on the blocking pool.
+    let mut expected = glider();
+    expected.set_track_ages(true);
+    expected.steps().take(8).for_each(drop);
+
+    let mut game = glider();
+    game.set_track_ages(true);
+    let populations: Vec<usize> = Runtime::new().unwrap().block_on(async {
+        let mut populations = Vec::new();
+        for _ in 0..8 {
+            populations.push(game.step_async().await.unwrap());
+        }
+        populations
+    });
+    assert_eq!(populations, vec![5; 8]);
+    assert_eq!(game.live_cells, expected.live_cells);
+    assert_eq!(game.generation_data, expected.generation_data);
+    assert_eq!(game.cell_ages, expected.cell_ages);
+    assert_eq!(game.generation(), 8);
+}
+
+#[test]
+fn simulate_async_matches_simulate() {
+    let mut expected = glider();
+    let kind = expected.simulate(30, None);
+
+    let mut game = glider();
+    let result = Runtime::new().unwrap().block_on(game.simulate_async(30)).unwrap();
+    assert_eq!(result.stabilization_kind, kind);
+    assert_eq!(result.generations, expected.generation_data);
+    assert_eq!(game.live_cells, expected.live_cells);
+}
+
+#[test]
+fn dropping_the_future_leaves_the_game_unchanged() {
+    let runtime = Runtime::new().unwrap();
+    let _entered = runtime.enter();
+    let mut game = glider();
+    // Long enough for the first poll to come back before the run is done.
+    game.set_stability_window(100_000);
+    {
+        // Polled once, so the run has started on the blocking pool.
+        let mut future = pin!(game.simulate_async(20_000));
+        assert!(future.as_mut().poll(&mut Context::from_waker(Waker::noop())).is_pending());
+    }
+    assert_eq!(game.live_cells, glider().live_cells);
+    assert_eq!(game.generation(), 0);
+}
@@ -0,0 +1,54 @@
+// `population_autocorrelation` bounds, the period of a glider gun, and
+// `GameOfLife::population_series` as its input.
+
+use powercela::stats::population_autocorrelation;
+use powercela::{GameOfLife, SimulationResult};
+
+fn gun_autocorrelation(max_lag: usize) -> Vec<f64> {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named("gosper-glider-gun", 0, 0).unwrap();
+    let kind = game.simulate(600, None);
+    let mut result = SimulationResult::from_game(game, kind, 0);
+    result.include_autocorrelation(max_lag);
+    result.autocorrelation.unwrap()
+}
+
+#[test]
+fn lag_zero_is_one_and_every_lag_is_bounded() {
+    let r = gun_autocorrelation(64);
+    assert_eq!(r.len(), 65);
+    assert_eq!(r[0], 1.0);
+    assert!(r.iter().all(|&r| (-1.0..=1.0).contains(&r)), "{:?}", r);
+}
+
+#[test]
+fn glider_gun_peaks_at_its_period() {
+    // The growing glider stream makes every lag correlate strongly, so look
+    // for the local peak on top of that trend.
+    let r = gun_autocorrelation(64);
+    let peak = (25..=35).max_by(|&a, &b| r[a].total_cmp(&r[b])).unwrap();
+    assert_eq!(peak, 30, "{:?}", &r[25..=35]);
+}
+
+#[test]
+fn constant_and_short_series() {
+    assert_eq!(population_autocorrelation(&[7; 10], 3), vec![1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(population_autocorrelation(&[1, 3], 3), vec![1.0, -0.5, 0.0, 0.0]);
+}
+
+#[test]
+fn population_series_lists_every_generation_in_order() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named("gosper-glider-gun", 0, 0).unwrap();
+    let kind = game.simulate(600, None);
+    let series = game.population_series();
+    assert_eq!(series.len(), game.generation() + 1);
+    assert_eq!(series, game.generation_data.values().copied().collect::<Vec<_>>());
+
+    let expected = population_autocorrelation(&series, 64);
+    let mut result = SimulationResult::from_game(game, kind, 0);
+    result.include_autocorrelation(64);
+    assert_eq!(result.autocorrelation.unwrap(), expected);
+}
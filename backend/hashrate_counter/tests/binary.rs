@@ -0,0 +1,62 @@
+// `write_binary` and `read_binary` round trips and bad headers.
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use powercela::formats::binary::{BINARY_MAGIC, BINARY_VERSION};
+use powercela::{read_binary, write_binary, GameOfLife, Point};
+
+fn temp_file(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("powercela-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn cells_survive_a_round_trip() {
+    let mut game = GameOfLife::new();
+    game.load_named("gosper-glider-gun", -20, 7).unwrap();
+    let path = temp_file("gun.pcla");
+    write_binary(&game.live_cells, &path).unwrap();
+    let cells = read_binary(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(cells, game.live_cells);
+
+    let path = temp_file("empty.pcla");
+    write_binary(&HashSet::new(), &path).unwrap();
+    let cells = read_binary(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(cells.is_empty());
+}
+
+#[test]
+fn a_huge_header_on_a_short_file_is_invalid_data() {
+    let mut bytes = BINARY_MAGIC.to_vec();
+    for field in [BINARY_VERSION as i32, i32::MAX, i32::MAX, 0, 0] {
+        bytes.extend_from_slice(&field.to_le_bytes());
+    }
+    let path = temp_file("huge.pcla");
+    fs::write(&path, &bytes).unwrap();
+    let error = read_binary(&path).unwrap_err();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn wrong_magic_is_invalid_data() {
+    let path = temp_file("magic.pcla");
+    fs::write(&path, [0u8; 24]).unwrap();
+    let error = read_binary(&path).unwrap_err();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn far_apart_cells_are_invalid_input() {
+    let cells: HashSet<Point> = [Point::new(0, 0), Point::new(2_000_000_000, 2_000_000_000)].into();
+    let path = temp_file("far.pcla");
+    let error = write_binary(&cells, &path).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    assert!(!path.exists());
+}
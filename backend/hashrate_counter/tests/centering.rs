@@ -0,0 +1,37 @@
+// Placing patterns around the origin.
+
+use powercela::{GameOfLife, Topology};
+
+fn midpoint(game: &GameOfLife) -> (f64, f64) {
+    let bbox = game.bounding_box().unwrap();
+    ((bbox.min_x + bbox.max_x) as f64 / 2.0, (bbox.min_y + bbox.max_y) as f64 / 2.0)
+}
+
+#[test]
+fn patterns_are_centered_on_the_origin() {
+    let patterns: [&[&str]; 4] = [&["1"], &["010", "001", "111"], &["1111", "1001"], &["11111", "10001", "10001", "11111"]];
+    for pattern in patterns {
+        let mut game = GameOfLife::new();
+        game.initialize_pattern_at_origin(pattern).unwrap();
+        let (x, y) = midpoint(&game);
+        assert!(x.abs() <= 1.0 && y.abs() <= 1.0, "{:?} has its midpoint at ({}, {})", pattern, x, y);
+    }
+}
+
+#[test]
+fn matches_explicit_offsets() {
+    let pattern = ["0110", "1001", "0110"];
+    let mut centered = GameOfLife::new();
+    centered.initialize_pattern_at_origin(&pattern).unwrap();
+    let mut explicit = GameOfLife::new();
+    explicit.initialize_pattern(&pattern, -2, -1).unwrap();
+    assert_eq!(centered.live_cells, explicit.live_cells);
+}
+
+#[test]
+fn a_torus_starts_at_the_corner() {
+    let mut game = GameOfLife::with_topology(Topology::Torus { width: 10, height: 10 });
+    game.initialize_pattern_at_origin(&["111"]).unwrap();
+    let bbox = game.bounding_box().unwrap();
+    assert_eq!((bbox.min_x, bbox.min_y), (0, 0));
+}
@@ -0,0 +1,37 @@
+// A cloned game simulates on independently of the original.
+
+use powercela::GameOfLife;
+
+#[test]
+fn a_clone_at_generation_50_runs_the_same_next_50() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named("r-pentomino", 0, 0).unwrap();
+    game.simulate(50, None);
+    assert_eq!(game.generation(), 50);
+
+    let mut copy = game.clone();
+    game.simulate(100, Some(50));
+    assert_eq!(copy.generation(), 50);
+    copy.simulate(100, Some(50));
+
+    assert_eq!(game.generation(), 100);
+    assert_eq!(game.generation_data.range(50..=100).count(), 51);
+    assert!(game.generation_data.range(50..=100).eq(copy.generation_data.range(50..=100)));
+    assert_eq!(game.live_cells, copy.live_cells);
+}
+
+#[test]
+fn changing_a_clone_leaves_the_original_alone() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named("r-pentomino", 0, 0).unwrap();
+    game.simulate(50, None);
+    let before = game.live_cells.clone();
+
+    let mut copy = game.clone();
+    copy.live_cells.clear();
+    copy.simulate(100, Some(50));
+    assert_eq!(game.live_cells, before);
+    assert_eq!(game.generation(), 50);
+}
@@ -0,0 +1,52 @@
+// `overlay` and the other set operations, and `xor_overlay` against them.
+
+use std::collections::HashSet;
+
+use powercela::{GameOfLife, Point};
+
+fn game(pattern: &[&str], x: i64, y: i64) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(pattern, x, y).unwrap();
+    game
+}
+
+#[test]
+fn xor_with_itself_is_empty() {
+    let mut glider = game(&["010", "001", "111"], 0, 0);
+    let cells = glider.live_cells.clone();
+    assert!(glider.xor_overlay(&cells).live_cells.is_empty());
+}
+
+#[test]
+fn xor_of_disjoint_sets_is_their_union() {
+    let mut glider = game(&["010", "001", "111"], 0, 0);
+    let block = game(&["11", "11"], 10, 10);
+    let mut union = glider.clone();
+    union.overlay(&block);
+    assert_eq!(glider.xor_overlay(&block.live_cells).live_cells, union.live_cells);
+}
+
+#[test]
+fn xor_with_one_cell_toggles_it() {
+    let mut blinker = game(&["111"], 0, 0);
+    blinker.xor_overlay(&HashSet::from([Point::new(1, 0)]));
+    assert_eq!(blinker.live_cells, HashSet::from([Point::new(0, 0), Point::new(2, 0)]));
+    blinker.xor_overlay(&HashSet::from([Point::new(1, 0)]));
+    assert_eq!(blinker.live_cells.len(), 3);
+}
+
+#[test]
+fn a_glider_and_a_blinker_overlay_to_their_union() {
+    let glider = game(&["010", "001", "111"], 0, 0);
+    let apart = game(&["111"], 10, 0);
+    assert_eq!(glider.clone().overlay(&apart).live_cells.len(), 8);
+
+    // The blinker covers the glider's bottom row.
+    let over = game(&["111"], 0, 2);
+    let union = glider.clone().overlay(&over).live_cells.clone();
+    assert_eq!(union.len(), 5);
+    assert_eq!(union, glider.live_cells);
+    assert_eq!(glider.clone().intersect(&over).live_cells.len(), 3);
+    assert_eq!(glider.clone().subtract(&over).live_cells.len(), 2);
+    assert_eq!(glider.clone().symmetric_difference(&over).live_cells.len(), 2);
+}
@@ -0,0 +1,40 @@
+// Moore-connected components of live cells.
+
+use std::collections::HashSet;
+
+use powercela::components::{count_components, label_components};
+use powercela::{GameOfLife, Point, SimulationResult};
+
+fn cells(points: &[(i64, i64)]) -> HashSet<Point> {
+    points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn diagonal_cells_are_connected() {
+    assert_eq!(count_components(&HashSet::new()), 0);
+    assert_eq!(count_components(&cells(&[(0, 0), (1, 1), (2, 0), (3, -1)])), 1);
+    assert_eq!(count_components(&cells(&[(0, 0), (2, 0), (0, 2), (2, 2)])), 4);
+}
+
+#[test]
+fn labels_follow_the_smallest_cell() {
+    let labels = label_components(&cells(&[(5, 5), (6, 5), (0, 0), (0, 1), (-3, 9)]));
+    assert_eq!(labels[&Point::new(-3, 9)], 0);
+    assert_eq!(labels[&Point::new(0, 0)], 1);
+    assert_eq!(labels[&Point::new(0, 1)], 1);
+    assert_eq!(labels[&Point::new(5, 5)], 2);
+    assert_eq!(labels[&Point::new(6, 5)], 2);
+}
+
+#[test]
+fn two_blinkers_stay_two_components() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.set_track_components(true);
+    game.initialize_pattern(&["111"], 0, 0).unwrap();
+    game.initialize_pattern(&["111"], 20, 20).unwrap();
+    let kind = game.simulate(10, None);
+    let series = SimulationResult::from_game(game, kind, 0).component_count_series.unwrap();
+    assert_eq!(series.len(), 11);
+    assert!(series.values().all(|&count| count == 2), "{:?}", series);
+}
@@ -0,0 +1,48 @@
+// `powercela_core::step` and `StabilityTracker` against `GameOfLife`.
+
+use std::collections::BTreeSet;
+
+use powercela::{GameOfLife, Neighborhood, Point, Rule, Topology};
+
+fn compare(pattern: &[&str], rule: Rule, topology: Topology, generations: usize) {
+    let mut game = GameOfLife::with_topology(topology);
+    game.set_rule(rule.clone());
+    game.initialize_pattern(pattern, 0, 0).unwrap();
+    let offsets = Neighborhood::Moore.offsets();
+    let mut cells: BTreeSet<Point> = game.live_cells.iter().copied().collect();
+
+    for snapshot in game.steps().take(generations) {
+        cells = powercela_core::step(&cells, topology, &offsets, |alive, neighbors| rule.next_state(alive, neighbors));
+        let expected: BTreeSet<Point> = snapshot.live_cells.into_iter().collect();
+        assert_eq!(cells, expected, "generation {}", snapshot.generation);
+    }
+}
+
+#[test]
+fn r_pentomino() {
+    compare(&["011", "110", "010"], Rule::conway(), Topology::Infinite, 100);
+}
+
+#[test]
+fn highlife_on_a_torus() {
+    compare(&["0110", "1100", "0100", "0011"], Rule::highlife(), Topology::Torus { width: 12, height: 10 }, 100);
+}
+
+// `powercela_core` alone is enough to run a pattern until it settles.
+#[test]
+fn stability_tracker_stops_like_simulate() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named("beacon", 0, 0).unwrap();
+    let expected = game.clone().simulate(1000, None);
+
+    let offsets = Neighborhood::Moore.offsets();
+    let rule = Rule::conway();
+    let mut cells: BTreeSet<Point> = game.live_cells.iter().copied().collect();
+    let mut tracker = powercela_core::StabilityTracker::new(game.stability_window(), cells.len());
+    let kind = (1..=1000).find_map(|generation| {
+        cells = powercela_core::step(&cells, Topology::Infinite, &offsets, |alive, neighbors| rule.next_state(alive, neighbors));
+        tracker.observe(generation, cells.len())
+    });
+    assert_eq!(kind, Some(expected));
+}
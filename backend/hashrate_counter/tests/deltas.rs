@@ -0,0 +1,62 @@
+// `include_deltas` against the populations it was computed from, and for
+// steady and growing patterns.
+
+use powercela::{GameOfLife, Rule, SimulationResult};
+
+fn deltas_of(mut game: GameOfLife, iterations: usize) -> Vec<i64> {
+    game.set_quiet(true);
+    game.set_stability_window(1000);
+    let kind = game.simulate(iterations, None);
+    let mut result = SimulationResult::from_game(game, kind, 0);
+    result.include_deltas(10);
+    result.deltas.unwrap().into_values().collect()
+}
+
+#[test]
+fn running_sum_of_deltas_gives_the_populations() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(&["011", "110", "010"], 0, 0).unwrap();
+    let kind = game.simulate(200, None);
+    let mut result = SimulationResult::from_game(game, kind, 0);
+    result.include_deltas(10);
+
+    let deltas = result.generation_deltas.as_ref().unwrap();
+    assert_eq!(deltas[&0], 5);
+    assert_eq!(deltas.len(), result.generations.len());
+    assert_eq!(deltas.range(1..).collect::<Vec<_>>(), result.deltas.as_ref().unwrap().iter().collect::<Vec<_>>());
+    let mut population = 0;
+    for (generation, delta) in deltas {
+        population += delta;
+        assert_eq!(population, result.generations[generation] as i64, "generation {}", generation);
+    }
+    assert_eq!(result.rolling_avg_growth.unwrap().len(), result.generations.len() - 1);
+}
+
+#[test]
+fn a_glider_never_changes_its_population() {
+    let mut game = GameOfLife::new();
+    game.load_named("glider", 0, 0).unwrap();
+    let deltas = deltas_of(game, 100);
+    assert_eq!(deltas.len(), 100);
+    assert!(deltas.iter().all(|&delta| delta == 0), "{:?}", deltas);
+}
+
+#[test]
+fn an_expanding_pattern_has_positive_deltas() {
+    // Under Life without death no cell dies, so the R-pentomino only grows.
+    let mut game = GameOfLife::new();
+    game.set_rule("B3/S012345678".parse::<Rule>().unwrap());
+    game.load_named("r-pentomino", 0, 0).unwrap();
+    let deltas = deltas_of(game, 100);
+    assert!(deltas.iter().all(|&delta| delta >= 0), "{:?}", deltas);
+    assert!(deltas.iter().filter(|&&delta| delta > 0).count() > 50);
+
+    // The glider gun adds one five-cell glider every 30 generations.
+    let mut gun = GameOfLife::new();
+    gun.load_named("gosper-glider-gun", 0, 0).unwrap();
+    let deltas = deltas_of(gun, 300);
+    for period in deltas.chunks(30).skip(1) {
+        assert_eq!(period.iter().sum::<i64>(), 5, "{:?}", period);
+    }
+}
@@ -0,0 +1,56 @@
+// Replaying `simulate_diff` output rebuilds the same cells as stepping directly.
+
+use std::collections::HashSet;
+
+use powercela::{GameOfLife, Point};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct DiffLine {
+    #[serde(rename = "gen")]
+    generation: usize,
+    born: Vec<[i64; 2]>,
+    died: Vec<[i64; 2]>,
+}
+
+fn game(pattern: &[&str]) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(pattern, 0, 0).unwrap();
+    game
+}
+
+fn replay_matches(pattern: &[&str], iterations: usize) {
+    let mut output = Vec::new();
+    game(pattern).simulate_diff(&mut output, iterations).unwrap();
+
+    let mut direct = game(pattern);
+    let mut expected = vec![direct.live_cells.clone()];
+    expected.extend(direct.steps().take(iterations).map(|snapshot| snapshot.live_cells));
+
+    let mut cells = HashSet::new();
+    let lines = String::from_utf8(output).unwrap();
+    assert!(lines.lines().count() > 1);
+    for (line, expected) in lines.lines().zip(&expected) {
+        let diff: DiffLine = serde_json::from_str(line).unwrap();
+        for [x, y] in diff.died {
+            assert!(cells.remove(&Point::new(x, y)), "generation {} kills a dead cell", diff.generation);
+        }
+        for [x, y] in diff.born {
+            assert!(cells.insert(Point::new(x, y)), "generation {} births a live cell", diff.generation);
+        }
+        assert_eq!(&cells, expected, "generation {}", diff.generation);
+    }
+}
+
+#[test]
+fn r_pentomino() {
+    replay_matches(&["011", "110", "010"], 300);
+}
+
+#[test]
+fn glider_until_it_stabilizes() {
+    // A glider's population never changes, so the run stops at the stability
+    // window and the output is shorter than the direct run.
+    replay_matches(&["010", "001", "111"], 500);
+}
@@ -0,0 +1,35 @@
+// `Display` draws the bounding box, cropped to `display_limit`.
+
+use powercela::GameOfLife;
+
+fn blinker() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(&["111"], 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn a_blinker_in_both_phases() {
+    let mut game = blinker();
+    assert_eq!(game.to_string(), "Generation 0, population 3\n###");
+    game.steps().next();
+    assert_eq!(game.to_string(), "Generation 1, population 3\n#\n#\n#");
+}
+
+#[test]
+fn an_empty_game_is_only_the_header() {
+    assert_eq!(GameOfLife::new().to_string(), "Generation 0, population 0");
+}
+
+#[test]
+fn a_large_pattern_is_cropped_around_its_center() {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(&["1010101", "0101010", "1010101", "0101010", "1010101"], 0, 0).unwrap();
+    game.set_display_limit(5, 4);
+    assert_eq!(game.to_string(), "Generation 0, population 18\n……………\n….#.…\n…#.#…\n……………");
+
+    // Only the columns are cut.
+    game.set_display_limit(5, 5);
+    assert_eq!(game.to_string(), "Generation 0, population 18\n…#.#…\n….#.…\n…#.#…\n….#.…\n…#.#…");
+}
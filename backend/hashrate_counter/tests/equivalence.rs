@@ -0,0 +1,44 @@
+// `patterns_equivalent` matches translated, rotated and mirrored copies.
+
+use std::collections::HashSet;
+
+use powercela::{patterns_equivalent, Point};
+
+const GLIDER: [(i64, i64); 5] = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+
+fn cells(points: impl IntoIterator<Item = (i64, i64)>) -> HashSet<Point> {
+    points.into_iter().map(|(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn a_rotated_glider_is_a_glider() {
+    let glider = cells(GLIDER);
+    // A quarter turn, then moved away from the origin.
+    let rotated = cells(GLIDER.map(|(x, y)| (-y + 100, x - 7)));
+    assert!(patterns_equivalent(&glider, &rotated, true));
+    assert!(!patterns_equivalent(&glider, &rotated, false));
+}
+
+#[test]
+fn a_glider_is_not_a_blinker() {
+    let glider = cells(GLIDER);
+    let blinker = cells([(0, 0), (1, 0), (2, 0)]);
+    assert!(!patterns_equivalent(&glider, &blinker, true));
+    // Same size, different shape.
+    let line = cells((0..5).map(|x| (x, 0)));
+    assert!(!patterns_equivalent(&glider, &line, true));
+}
+
+#[test]
+fn symmetric_patterns_match_themselves_without_rotation() {
+    let block = cells([(0, 0), (1, 0), (0, 1), (1, 1)]);
+    let moved = cells([(5, -3), (6, -3), (5, -2), (6, -2)]);
+    assert!(patterns_equivalent(&block, &moved, false));
+    assert!(patterns_equivalent(&block, &moved, true));
+
+    // A blinker's two phases are each other's quarter turn.
+    let horizontal = cells([(0, 0), (1, 0), (2, 0)]);
+    let vertical = cells([(1, -1), (1, 0), (1, 1)]);
+    assert!(patterns_equivalent(&horizontal, &vertical, true));
+    assert!(!patterns_equivalent(&horizontal, &vertical, false));
+}
@@ -0,0 +1,56 @@
+// `simulate_events` reports every birth and death.
+
+use std::collections::BTreeMap;
+
+use powercela::{GameOfLife, SimulationEvent};
+
+fn blinker() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(&["111"], 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn blinker_flips_two_cells_each_way_per_generation() {
+    let mut game = blinker();
+    let mut counts: BTreeMap<usize, (usize, usize)> = BTreeMap::new();
+    assert_eq!(
+        game.simulate_events(10, |generation, event| {
+            let (born, died) = counts.entry(generation).or_default();
+            match event {
+                SimulationEvent::CellBorn(_) => *born += 1,
+                SimulationEvent::CellDied(_) => *died += 1,
+            }
+        }),
+        None
+    );
+    assert_eq!(counts.keys().copied().collect::<Vec<_>>(), (1..=10).collect::<Vec<_>>());
+    // The ends die and the cells above and below the middle are born, then
+    // back again: 4 births and 4 deaths per period-2 cycle.
+    assert!(counts.values().all(|&counts| counts == (2, 2)), "{:?}", counts);
+    for cycle in [1..=2, 3..=4, 9..=10] {
+        let (born, died) = cycle.map(|generation| counts[&generation]).fold((0, 0), |(b, d), (born, died)| (b + born, d + died));
+        assert_eq!((born, died), (4, 4));
+    }
+}
+
+#[test]
+fn events_rebuild_each_generation() {
+    let mut game = blinker();
+    let start = game.live_cells.clone();
+    let mut cells = start.clone();
+    let mut generations = Vec::new();
+    game.simulate_events(2, |generation, event| {
+        match event {
+            SimulationEvent::CellBorn(cell) => assert!(cells.insert(cell)),
+            SimulationEvent::CellDied(cell) => assert!(cells.remove(&cell)),
+        }
+        if generations.last() != Some(&generation) {
+            generations.push(generation);
+        }
+    });
+    assert_eq!(generations, [1, 2]);
+    assert_eq!(cells, start);
+    assert_eq!(cells, game.live_cells);
+}
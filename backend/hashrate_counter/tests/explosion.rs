@@ -0,0 +1,32 @@
+// `max_population` stops a growing pattern at its first generation over the
+// limit.
+
+use powercela::{GameOfLife, StabilizationKind};
+
+fn gun() -> GameOfLife {
+    let mut game = GameOfLife::builder().max_population(100).build().unwrap();
+    game.set_quiet(true);
+    game.load_named("gosper-glider-gun", 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn a_glider_gun_explodes() {
+    let mut unbounded = gun();
+    unbounded.set_max_population(None);
+    unbounded.simulate(1000, None);
+    let (&first_over, &population) = unbounded.generation_data.iter().find(|(_, &population)| population > 100).unwrap();
+
+    let mut game = gun();
+    assert_eq!(game.simulate(1000, None), StabilizationKind::Explosion { generation: first_over, population });
+    assert_eq!(game.generation(), first_over);
+    assert_eq!(game.live_cells.len(), population);
+}
+
+#[test]
+fn a_pattern_under_the_limit_runs_on() {
+    let mut game = GameOfLife::builder().max_population(100).build().unwrap();
+    game.set_quiet(true);
+    game.load_named("pulsar", 0, 0).unwrap();
+    assert!(game.simulate(200, None).is_settled());
+}
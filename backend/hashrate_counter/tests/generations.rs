@@ -0,0 +1,64 @@
+// Parsing Generations rules, and Star Wars cells decaying through their states.
+
+use std::collections::HashMap;
+
+use powercela::{GenerationsGame, GenerationsRule, Point, StabilizationKind};
+
+fn star_wars(cells: &[(i64, i64)]) -> GenerationsGame {
+    let mut game = GenerationsGame::new(GenerationsRule::star_wars());
+    game.set_quiet(true);
+    game.cells = cells.iter().map(|&(x, y)| (Point::new(x, y), 1)).collect();
+    game
+}
+
+#[test]
+fn the_digit_form_lists_births_first() {
+    assert_eq!("2/345/4".parse::<GenerationsRule>().unwrap(), GenerationsRule::star_wars());
+    assert_eq!("B2/S345/C4".parse::<GenerationsRule>().unwrap(), GenerationsRule::star_wars());
+    assert_eq!("S345/B2/C4".parse::<GenerationsRule>().unwrap(), GenerationsRule::star_wars());
+    assert_eq!(GenerationsRule::star_wars().to_string(), "B2/S345/C4");
+
+    // Brian's Brain: born on two, never survives, three states.
+    let brain = "2//3".parse::<GenerationsRule>().unwrap();
+    assert_eq!(brain.to_string(), "B2/S/C3");
+    assert_ne!("345/2/4".parse::<GenerationsRule>().unwrap(), GenerationsRule::star_wars());
+
+    for invalid in ["2/345", "2/345/1", "2/345/x", "B2/S345/C4/5"] {
+        assert!(invalid.parse::<GenerationsRule>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn a_lone_star_wars_cell_decays_through_each_state() {
+    let mut game = star_wars(&[(0, 0)]);
+    let mut states = Vec::new();
+    for generation in 1..=3 {
+        let kind = game.simulate_generations(generation);
+        assert_eq!(kind, if generation < 3 { StabilizationKind::None } else { StabilizationKind::Extinction(3) });
+        states.push(game.cells.get(&Point::new(0, 0)).copied());
+    }
+    // Alive, then dying in states 2 and 3, then dead.
+    assert_eq!(states, [Some(2), Some(3), None]);
+    assert_eq!(game.generation_data.values().copied().collect::<Vec<_>>(), [1, 1, 1, 0]);
+}
+
+#[test]
+fn dying_cells_do_not_count_as_neighbors() {
+    // Two cells side by side: neither survives with one neighbor, and the four
+    // cells next to both are born.
+    let mut game = star_wars(&[(0, 0), (1, 0)]);
+    game.simulate_generations(1);
+    let expected: HashMap<Point, u8> = [((0, 0), 2), ((1, 0), 2), ((0, -1), 1), ((1, -1), 1), ((0, 1), 1), ((1, 1), 1)]
+        .into_iter()
+        .map(|((x, y), state)| (Point::new(x, y), state))
+        .collect();
+    assert_eq!(game.cells, expected);
+
+    // Counting the dying pair, each new cell would have the three neighbors it
+    // needs to survive; it only has one.
+    game.simulate_generations(2);
+    for (x, y) in [(0, -1), (1, -1), (0, 1), (1, 1)] {
+        assert_eq!(game.cells[&Point::new(x, y)], 2, "({}, {})", x, y);
+    }
+    assert_eq!(game.cells[&Point::new(0, 0)], 3);
+}
@@ -0,0 +1,79 @@
+// Translating, rotating and flipping keep a pattern's cells, and the latter
+// two the center of its bounding box.
+
+use std::collections::HashSet;
+
+use powercela::{GameOfLife, Point};
+
+fn game(pattern: &[&str]) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(pattern, 3, -2).unwrap();
+    game
+}
+
+#[test]
+fn four_quarter_turns_give_back_the_original() {
+    // The R-pentomino has no symmetry, and an even width and odd height.
+    for pattern in [&["011", "110", "010"][..], &["0110", "1100", "0100"][..]] {
+        let mut game = game(pattern);
+        let original = game.live_cells.clone();
+        game.rotate_90();
+        assert_ne!(game.live_cells, original);
+        game.rotate_90().rotate_90().rotate_90();
+        assert_eq!(game.live_cells, original, "{:?}", pattern);
+
+        game.rotate_90().rotate_270();
+        assert_eq!(game.live_cells, original);
+        game.rotate_180().rotate_180();
+        assert_eq!(game.live_cells, original);
+    }
+}
+
+#[test]
+fn flipping_a_symmetric_pattern_changes_nothing() {
+    let mut game = game(&["01110", "10001", "01110"]);
+    let original = game.live_cells.clone();
+    game.flip_horizontal();
+    assert_eq!(game.live_cells, original);
+    game.flip_vertical();
+    assert_eq!(game.live_cells, original);
+
+    let mut glider = self::game(&["010", "001", "111"]);
+    let original = glider.live_cells.clone();
+    glider.flip_horizontal();
+    assert_ne!(glider.live_cells, original);
+    glider.flip_horizontal();
+    assert_eq!(glider.live_cells, original);
+}
+
+#[test]
+fn transforms_at_the_edge_of_the_plane_do_not_overflow() {
+    let mut game = GameOfLife::new();
+    game.live_cells = HashSet::from([Point::new(i64::MIN, i64::MIN), Point::new(i64::MIN + 1, i64::MIN)]);
+    game.rotate_90().flip_horizontal().flip_vertical().rotate_180().rotate_270();
+    assert_eq!(game.live_cells.len(), 2);
+}
+
+#[test]
+fn a_translated_pattern_keeps_its_population() {
+    let mut game = game(&["011", "110", "010"]);
+    let original = game.live_cells.clone();
+    game.translate(-40, 17);
+    assert_eq!(game.live_cells.len(), original.len());
+    let moved: HashSet<Point> = original.iter().map(|cell| Point::new(cell.x - 40, cell.y + 17)).collect();
+    assert_eq!(game.live_cells, moved);
+}
+
+#[test]
+fn a_translated_pattern_evolves_like_the_original() {
+    let mut original = game(&["011", "110", "010"]);
+    original.set_quiet(true);
+    let mut moved = original.clone();
+    moved.translate(1000, -1000);
+
+    original.simulate(200, None);
+    moved.simulate(200, None);
+    assert_eq!(moved.generation_data, original.generation_data);
+    moved.translate(-1000, 1000);
+    assert_eq!(moved.live_cells, original.live_cells);
+}
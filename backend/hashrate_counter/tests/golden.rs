@@ -0,0 +1,96 @@
+// Populations of well-known patterns checked against the committed files in
+// tests/fixtures. Run with UPDATE_FIXTURES=1 to rewrite them after an
+// intended change in behavior.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use powercela::GameOfLife;
+
+const ITERATIONS: usize = 100;
+
+fn run_and_compare(name: &str, pattern: &[&str], iterations: usize) {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(pattern, 0, 0).unwrap();
+    game.simulate(iterations, None);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(format!("{}.json", name));
+    if env::var_os("UPDATE_FIXTURES").is_some() {
+        fs::write(&path, serde_json::to_string_pretty(&game.generation_data).unwrap() + "\n").unwrap();
+        return;
+    }
+    let fixture = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    let expected: BTreeMap<usize, usize> = serde_json::from_str(&fixture).unwrap();
+    assert_eq!(game.generation_data, expected, "{} differs from {}", name, path.display());
+}
+
+#[test]
+fn glider() {
+    run_and_compare("glider", &["010", "001", "111"], ITERATIONS);
+}
+
+#[test]
+fn blinker() {
+    run_and_compare("blinker", &["111"], ITERATIONS);
+}
+
+#[test]
+fn beacon() {
+    run_and_compare("beacon", &["1100", "1100", "0011", "0011"], ITERATIONS);
+}
+
+#[test]
+fn pulsar() {
+    run_and_compare(
+        "pulsar",
+        &[
+            "0011100011100",
+            "0000000000000",
+            "1000010100001",
+            "1000010100001",
+            "1000010100001",
+            "0011100011100",
+            "0000000000000",
+            "0011100011100",
+            "1000010100001",
+            "1000010100001",
+            "1000010100001",
+            "0000000000000",
+            "0011100011100",
+        ],
+        ITERATIONS,
+    );
+}
+
+#[test]
+fn r_pentomino() {
+    run_and_compare("r-pentomino", &["011", "110", "010"], ITERATIONS);
+}
+
+#[test]
+fn acorn() {
+    run_and_compare("acorn", &["0100000", "0001000", "1100111"], ITERATIONS);
+}
+
+#[test]
+fn diehard() {
+    run_and_compare("diehard", &["00000010", "11000000", "01000111"], ITERATIONS);
+}
+
+#[test]
+fn lwss() {
+    run_and_compare("lwss", &["01001", "10000", "10001", "11110"], ITERATIONS);
+}
+
+#[test]
+fn block() {
+    run_and_compare("block", &["11", "11"], ITERATIONS);
+}
+
+#[test]
+fn tub() {
+    run_and_compare("tub", &["010", "101", "010"], ITERATIONS);
+}
@@ -0,0 +1,85 @@
+// The Simulator gRPC service (grpc feature).
+#![cfg(feature = "grpc")]
+
+use powercela::grpc::proto::simulator_server::SimulatorServer;
+use powercela::grpc::proto::{Empty, GenerationEvent, RunRequest};
+use powercela::grpc::{SimulatorClient, SimulatorService, MAX_ITERATIONS};
+use powercela::GameOfLife;
+use tokio::net::TcpListener;
+use tokio::runtime::Runtime;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Channel, Server};
+use tonic::Code;
+
+async fn connect() -> SimulatorClient<Channel> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = Server::builder().add_service(SimulatorServer::new(SimulatorService));
+    tokio::spawn(server.serve_with_incoming(TcpIncoming::from(listener)));
+    SimulatorClient::connect(format!("http://{}", address)).await.unwrap()
+}
+
+fn run_request(pattern_flat: &str, split_amount: u64, iterations: u64, rule: &str) -> RunRequest {
+    RunRequest { pattern_flat: pattern_flat.to_string(), split_amount, iterations, rule: rule.to_string() }
+}
+
+async fn events(client: &mut SimulatorClient<Channel>, request: RunRequest) -> Vec<GenerationEvent> {
+    let mut stream = client.run(request).await.unwrap().into_inner();
+    let mut events = Vec::new();
+    while let Some(event) = stream.message().await.unwrap() {
+        events.push(event);
+    }
+    events
+}
+
+#[test]
+fn streams_each_generation() {
+    Runtime::new().unwrap().block_on(async {
+        let mut client = connect().await;
+        let glider = events(&mut client, run_request("010001111", 3, 10, "")).await;
+        let expected: Vec<GenerationEvent> =
+            (0..=10).map(|generation| GenerationEvent { generation, population: 5, stabilized: false }).collect();
+        assert_eq!(glider, expected);
+
+        // A block stops as a fixed point once its population held for the stability window.
+        let block = events(&mut client, run_request("1111", 2, 200, "")).await;
+        let last = block.last().unwrap();
+        assert!(last.stabilized && last.generation < 200);
+        assert!(block[..block.len() - 1].iter().all(|event| !event.stabilized));
+    });
+}
+
+#[test]
+fn applies_the_rule() {
+    Runtime::new().unwrap().block_on(async {
+        let mut client = connect().await;
+        let events = events(&mut client, run_request("11", 2, 3, "B2/S")).await;
+
+        let mut game = GameOfLife::with_rule("B2/S".parse().unwrap());
+        game.set_quiet(true);
+        game.initialize_pattern_at_origin(&["11"]).unwrap();
+        game.simulate(3, None);
+        let populations: Vec<u64> = game.generation_data.values().map(|&population| population as u64).collect();
+        assert_eq!(events.iter().map(|event| event.population).collect::<Vec<_>>(), populations);
+    });
+}
+
+#[test]
+fn lists_patterns_and_rejects_bad_requests() {
+    Runtime::new().unwrap().block_on(async {
+        let mut client = connect().await;
+        let patterns = client.get_patterns(Empty {}).await.unwrap().into_inner().patterns;
+        assert!(patterns.iter().any(|pattern| pattern.name == "glider" && pattern.width == 3 && pattern.height == 3));
+
+        let status = client.run(run_request("012", 3, 10, "")).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let status = client.run(run_request("111", 3, 10, "B3/S23/X")).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let status = client.run(run_request("111", 0, 10, "")).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        for iterations in [MAX_ITERATIONS as u64 + 1, u64::MAX] {
+            let status = client.run(run_request("111", 3, iterations, "")).await.unwrap_err();
+            assert_eq!(status.code(), Code::InvalidArgument);
+        }
+    });
+}
@@ -0,0 +1,92 @@
+// Hashlife against the naive stepper, and its largest universe.
+
+use std::collections::HashSet;
+
+use powercela::{GameOfLife, HashLifeEngine, Point, Rule, StabilizationKind, UniverseFull};
+
+fn named(name: &str) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named(name, 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn glider_matches_the_naive_stepper_after_1024_generations() {
+    let mut hashlife = named("glider");
+    hashlife.simulate_hashlife(1024);
+    // A glider's population never changes, so keep the naive run from
+    // stopping as a fixed point.
+    let mut naive = named("glider");
+    naive.set_stability_window(2000);
+    assert_eq!(naive.simulate(1024, None), StabilizationKind::None);
+    assert_eq!(hashlife.generation(), 1024);
+    assert_eq!(hashlife.generation_data[&1024], naive.live_cells.len());
+    assert_eq!(hashlife.live_cells, naive.live_cells);
+}
+
+#[test]
+fn still_life_runs_all_of_usize_max() {
+    let mut game = named("block");
+    assert_eq!(game.simulate_hashlife(usize::MAX), StabilizationKind::None);
+    assert_eq!(game.generation(), usize::MAX);
+    assert_eq!(game.live_cells.len(), 4);
+}
+
+#[test]
+fn spaceship_stops_at_the_edge_of_the_universe() {
+    let mut game = named("glider");
+    assert_eq!(game.simulate_hashlife(usize::MAX), StabilizationKind::None);
+    assert!(game.generation() < usize::MAX);
+    assert_eq!(game.live_cells.len(), 5);
+}
+
+#[test]
+fn steps_too_large_for_the_universe_fail() {
+    let glider: HashSet<Point> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].into_iter().map(|(x, y)| Point::new(x, y)).collect();
+    let mut engine = HashLifeEngine::new(&glider, Rule::conway());
+    assert_eq!(engine.step(63).err(), Some(UniverseFull { generation: 0 }));
+    assert_eq!(engine.generation(), 0);
+    assert_eq!(engine.population(), 5);
+}
+
+#[test]
+fn checkpoints_double_then_jump_the_rest() {
+    let mut game = named("glider");
+    game.simulate_hashlife(1000);
+    let checkpoints: Vec<usize> = game.generation_data.keys().copied().collect();
+    assert_eq!(checkpoints, [0, 1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 768, 896, 960, 992, 1000]);
+}
+
+#[test]
+fn the_cell_limit_stops_at_the_first_power_of_two_past_it() {
+    const REPLICATOR: [&str; 5] = ["00111", "01001", "10001", "10010", "11100"];
+    let game = || {
+        let mut game = GameOfLife::builder().rule(Rule::highlife()).max_cells(100).build().unwrap();
+        game.set_quiet(true);
+        game.initialize_pattern_centered(&REPLICATOR).unwrap();
+        game
+    };
+    let mut naive = game();
+    naive.set_max_cells(None);
+    naive.set_stability_window(1000);
+    naive.simulate(256, None);
+    let powers = (0..=8).map(|k| 1usize << k);
+    let first = powers.clone().find(|generation| naive.generation_data[generation] > 100).unwrap();
+
+    let mut hashlife = game();
+    let kind = hashlife.simulate_hashlife(1 << 40);
+    assert_eq!(kind, StabilizationKind::MemoryLimitExceeded { generation: first, count: naive.generation_data[&first] });
+    assert!(powers.take_while(|&generation| generation < first).all(|generation| hashlife.generation_data[&generation] <= 100));
+}
+
+#[test]
+fn extinction_is_reported_at_the_first_empty_checkpoint() {
+    let mut naive = named("diehard");
+    assert_eq!(naive.simulate(1000, None), StabilizationKind::Extinction(130));
+
+    let mut hashlife = named("diehard");
+    assert_eq!(hashlife.simulate_hashlife(1000), StabilizationKind::Extinction(256));
+    assert_eq!(hashlife.generation_data[&128], naive.generation_data[&128]);
+    assert!(hashlife.live_cells.is_empty());
+}
@@ -0,0 +1,50 @@
+// Generations alive per cell, and the heatmap image.
+
+use std::env;
+
+use powercela::render::write_heatmap_png;
+use powercela::{GameOfLife, Point, SimulationResult, StabilizationKind};
+
+fn blinker_heatmap() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.set_track_heatmap(true);
+    game.initialize_pattern(&["111"], 0, 0).unwrap();
+    game.simulate(10, None);
+    game
+}
+
+#[test]
+fn blinker_counts() {
+    let heatmap = blinker_heatmap().compute_heatmap();
+    assert_eq!(heatmap.len(), 5);
+    // The center is always alive; the ends of each phase every other generation.
+    assert_eq!(heatmap[&Point::new(1, 0)], 11);
+    assert_eq!(heatmap[&Point::new(0, 0)], 6);
+    assert_eq!(heatmap[&Point::new(1, 1)], 5);
+}
+
+#[test]
+fn result_keys_are_x_comma_y() {
+    let game = blinker_heatmap();
+    let result = SimulationResult::from_game(game, StabilizationKind::None, 0);
+    let heatmap = result.heatmap.unwrap();
+    assert_eq!(heatmap["1,0"], 11);
+    assert_eq!(heatmap["1,-1"], 5);
+}
+
+#[test]
+fn png_is_written() {
+    let path = env::temp_dir().join(format!("powercela-heatmap-{}.png", std::process::id()));
+    write_heatmap_png(&blinker_heatmap().compute_heatmap(), &path).unwrap();
+    let image = image::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!((image.width(), image.height()), (24, 24));
+}
+
+#[test]
+fn top_cells_are_ranked() {
+    let game = blinker_heatmap();
+    assert_eq!(game.top_cells(3), vec![(Point::new(1, 0), 11), (Point::new(0, 0), 6), (Point::new(2, 0), 6)]);
+    assert_eq!(game.top_cells(100).len(), 5);
+}
@@ -0,0 +1,29 @@
+// `load_from_image` keeps the dark, mostly opaque pixels of
+// tests/fixtures/image-3x3.png:
+//
+//   black  white              black
+//   white  transparent black  gray 100
+//   gray 200  black at alpha 200  white
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use powercela::{GameOfLife, Point};
+
+fn load(threshold: u8) -> HashSet<Point> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/image-3x3.png");
+    let mut game = GameOfLife::new();
+    game.load_from_image(&path, threshold).unwrap();
+    game.live_cells
+}
+
+fn cells(points: &[(i64, i64)]) -> HashSet<Point> {
+    points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn pixels_darker_than_the_threshold_are_alive() {
+    assert_eq!(load(128), cells(&[(0, 0), (2, 0), (2, 1), (1, 2)]));
+    assert_eq!(load(255), cells(&[(0, 0), (2, 0), (2, 1), (0, 2), (1, 2)]));
+    assert_eq!(load(0), HashSet::new());
+}
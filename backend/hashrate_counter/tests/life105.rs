@@ -0,0 +1,37 @@
+// Life 1.05 export.
+
+use std::collections::HashSet;
+
+use powercela::formats::life105::export_life105;
+use powercela::{GameOfLife, Point};
+
+fn round_trip(cells: &HashSet<Point>) -> HashSet<Point> {
+    let mut game = GameOfLife::new();
+    game.load_life105(&export_life105(cells, "test")).unwrap();
+    game.live_cells
+}
+
+#[test]
+fn writes_a_glider() {
+    let glider: HashSet<Point> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].iter().map(|&(x, y)| Point::new(x, y)).collect();
+    assert_eq!(export_life105(&glider, "Glider"), "#Life 1.05\n#N Glider\n#P 0 0\n.*.\n..*\n***\n");
+    assert_eq!(round_trip(&glider), glider);
+}
+
+#[test]
+fn empty_patterns_have_no_blocks() {
+    assert_eq!(export_life105(&HashSet::new(), "Empty"), "#Life 1.05\n#N Empty\n");
+    assert!(round_trip(&HashSet::new()).is_empty());
+}
+
+#[test]
+fn wide_patterns_split_into_blocks() {
+    // 200 columns apart, with an empty row in the middle strip.
+    let cells: HashSet<Point> =
+        [(-50, -3), (-49, 4), (40, 0), (40, 2), (149, 7)].iter().map(|&(x, y)| Point::new(x, y)).collect();
+    let exported = export_life105(&cells, "Wide");
+    assert_eq!(exported.lines().filter(|line| line.starts_with("#P")).count(), 3);
+    assert!(exported.lines().all(|line| line.len() <= 80));
+    assert!(exported.contains("#P 30 0\n"));
+    assert_eq!(round_trip(&cells), cells);
+}
@@ -0,0 +1,42 @@
+// Reading the Life 1.06 files in tests/fixtures.
+
+use std::fs;
+use std::path::PathBuf;
+
+use powercela::formats::ParseError;
+use powercela::{patterns_equivalent, GameOfLife, Point};
+
+fn load(name: &str) -> GameOfLife {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name);
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_life106(&fs::read_to_string(path).unwrap()).unwrap();
+    game
+}
+
+#[test]
+fn reads_the_glider() {
+    let game = load("glider.lif");
+    assert_eq!(game.live_cells.len(), 5);
+    assert!(game.live_cells.contains(&Point::new(0, -1)) && game.live_cells.contains(&Point::new(-1, 1)));
+
+    let mut named = GameOfLife::new();
+    named.load_named("glider", 0, 0).unwrap();
+    assert!(patterns_equivalent(&game.live_cells, &named.live_cells, false));
+}
+
+#[test]
+fn reads_the_pulsar() {
+    let mut game = load("pulsar.lif");
+    assert_eq!(game.live_cells.len(), 48);
+    game.simulate(3, None);
+    assert_eq!(game.generation_data.values().copied().collect::<Vec<_>>(), [48, 56, 72, 48]);
+}
+
+#[test]
+fn rejects_a_bad_header_and_bad_coordinates() {
+    let mut game = GameOfLife::new();
+    assert_eq!(game.load_life106("#Life 1.05\n0 0\n"), Err(ParseError::InvalidHeader("#Life 1.05".to_string())));
+    assert_eq!(game.load_life106("#Life 1.06\n0 0\n1 x\n"), Err(ParseError::InvalidCoordinate(3, "1 x".to_string())));
+    assert!(game.live_cells.is_empty());
+}
@@ -0,0 +1,53 @@
+// Single-cell edits with `set_cell` and `apply_diff`.
+
+use powercela::{GameOfLife, Point, Topology};
+
+fn glider() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(&["010", "001", "111"], 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn toggling_twice_restores_the_pattern() {
+    let mut game = glider();
+    let original = game.live_cells.clone();
+    for (x, y) in [(0, 0), (1, 0)] {
+        let alive = game.live_cells.contains(&Point::new(x, y));
+        game.set_cell(x, y, !alive);
+        assert_ne!(game.live_cells, original);
+        game.set_cell(x, y, alive);
+        assert_eq!(game.live_cells, original);
+    }
+}
+
+#[test]
+fn setting_a_dead_cell_alive_adds_one() {
+    let mut game = glider();
+    game.set_cell(10, 10, true);
+    assert_eq!(game.live_cells.len(), 6);
+    game.set_cell(10, 10, true);
+    assert_eq!(game.live_cells.len(), 6);
+    game.set_cell(-5, 3, false);
+    assert_eq!(game.live_cells.len(), 6);
+}
+
+#[test]
+fn apply_diff_steps_a_glider() {
+    let mut game = glider();
+    let mut next = glider();
+    next.steps().next();
+    let born: Vec<Point> = next.live_cells.difference(&game.live_cells).copied().collect();
+    let died: Vec<Point> = game.live_cells.difference(&next.live_cells).copied().collect();
+    game.apply_diff(&born, &died);
+    assert_eq!(game.live_cells, next.live_cells);
+}
+
+#[test]
+fn cells_wrap_on_a_torus() {
+    let mut game = GameOfLife::with_topology(Topology::Torus { width: 8, height: 8 });
+    game.set_cell(-1, 9, true);
+    assert!(game.live_cells.contains(&Point::new(7, 1)));
+    game.apply_diff(&[], &[Point::new(15, 1)]);
+    assert!(game.live_cells.is_empty());
+}
@@ -0,0 +1,63 @@
+// Neighborhoods too large for the u8 neighbor counts, built-in or custom, are
+// rejected by every way of setting one; a blinker under Von Neumann.
+
+use powercela::{BuildError, GameOfLife, Neighborhood, StabilizationKind};
+
+#[test]
+fn setters_reject_more_than_255_neighbors() {
+    // Radius 8 has 17 * 17 - 1 = 288 neighbors.
+    assert_eq!(
+        GameOfLife::with_neighborhood(Neighborhood::MooreRadius(8)).err(),
+        Some(BuildError::NeighborhoodTooLarge(288))
+    );
+    assert_eq!(
+        GameOfLife::builder().neighborhood(Neighborhood::MooreRadius(8)).build().err(),
+        Some(BuildError::NeighborhoodTooLarge(288))
+    );
+
+    let mut game = GameOfLife::new();
+    assert_eq!(game.set_neighborhood(Neighborhood::MooreRadius(8)), Err(BuildError::NeighborhoodTooLarge(288)));
+    assert_eq!(game.neighborhood(), &Neighborhood::Moore);
+}
+
+#[test]
+fn largest_allowed_radius_simulates() {
+    // Radius 7 has 224 neighbors; a full 15x15 square gives its middle cell
+    // all of them. The square dies of overcrowding within two generations.
+    let mut game = GameOfLife::with_neighborhood(Neighborhood::MooreRadius(7)).unwrap();
+    game.set_quiet(true);
+    let rows = vec!["1".repeat(15); 15];
+    game.initialize_pattern(&rows.iter().map(String::as_str).collect::<Vec<_>>(), 0, 0).unwrap();
+    assert_eq!(game.simulate(3, None), StabilizationKind::Extinction(2));
+}
+
+#[test]
+fn custom_neighborhoods_are_limited_by_their_offset_count() {
+    let offsets = |n: i32| Neighborhood::Custom((1..=n).map(|dx| (dx, 0)).collect());
+    assert_eq!(GameOfLife::with_neighborhood(offsets(256)).err(), Some(BuildError::NeighborhoodTooLarge(256)));
+    assert_eq!(
+        GameOfLife::builder().neighborhood(offsets(256)).build().err(),
+        Some(BuildError::NeighborhoodTooLarge(256))
+    );
+    let mut game = GameOfLife::new();
+    assert!(game.set_neighborhood(offsets(256)).is_err());
+
+    // At 255, a row of 256 cells gives the cells at its right end a count of
+    // 255 without overflowing.
+    let mut game = GameOfLife::with_neighborhood(offsets(255)).unwrap();
+    game.set_quiet(true);
+    game.initialize_pattern(&["1".repeat(256).as_str()], 0, 0).unwrap();
+    game.simulate(1, None);
+    assert_eq!(game.generation(), 1);
+}
+
+#[test]
+fn blinker_does_not_oscillate_under_von_neumann() {
+    // Only the middle cell has two orthogonal neighbors, and no dead cell has
+    // three, so the middle cell is left alone and dies the generation after.
+    let mut game = GameOfLife::with_neighborhood(Neighborhood::VonNeumann).unwrap();
+    game.set_quiet(true);
+    game.initialize_pattern(&["111"], 0, 0).unwrap();
+    assert_eq!(game.simulate(100, None), StabilizationKind::Extinction(2));
+    assert_eq!(game.generation_data.values().copied().collect::<Vec<_>>(), [3, 1, 0]);
+}
@@ -0,0 +1,45 @@
+// Distribution of live neighbor counts among live cells.
+
+use std::collections::HashSet;
+
+use powercela::{neighbor_count_distribution, GameOfLife, Point, SimulationResult, StabilizationKind};
+
+fn cells(points: &[(i64, i64)]) -> HashSet<Point> {
+    points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn counts_cells_by_live_neighbors() {
+    assert_eq!(neighbor_count_distribution(&HashSet::new()), [0; 9]);
+    assert_eq!(neighbor_count_distribution(&cells(&[(0, 0), (5, 5)])), [2, 0, 0, 0, 0, 0, 0, 0, 0]);
+    // A block: every cell touches the other three.
+    assert_eq!(neighbor_count_distribution(&cells(&[(0, 0), (0, 1), (1, 0), (1, 1)])), [0, 0, 0, 4, 0, 0, 0, 0, 0]);
+    // A blinker: two ends with one neighbor around a middle with two.
+    assert_eq!(neighbor_count_distribution(&cells(&[(0, 0), (1, 0), (2, 0)])), [0, 2, 1, 0, 0, 0, 0, 0, 0]);
+    // The middle of a full 3x3 square sees all eight.
+    let square: Vec<(i64, i64)> = (0..3).flat_map(|x| (0..3).map(move |y| (x, y))).collect();
+    assert_eq!(neighbor_count_distribution(&cells(&square)), [0, 0, 0, 4, 0, 4, 0, 0, 1]);
+}
+
+#[test]
+fn distribution_sums_to_the_population() {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(&["010", "001", "111"], 0, 0).unwrap();
+    game.simulate(20, None);
+    let distribution = neighbor_count_distribution(&game.live_cells);
+    assert_eq!(distribution.iter().sum::<usize>(), game.live_cells.len());
+}
+
+#[test]
+fn result_leaves_the_distribution_out_by_default() {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(&["111"], 0, 0).unwrap();
+    game.simulate(2, None);
+    let mut result = SimulationResult::from_game(game, StabilizationKind::None, 0);
+    let json = serde_json::to_string(&result).unwrap();
+    assert!(!json.contains("neighborDistributionFinal"));
+
+    result.neighbor_distribution_final = Some([0, 2, 1, 0, 0, 0, 0, 0, 0]);
+    let json = serde_json::to_string(&result).unwrap();
+    assert!(json.contains(r#""neighborDistributionFinal":[0,2,1,0,0,0,0,0,0]"#));
+}
@@ -0,0 +1,60 @@
+// Results written by `output::write_result` read back unchanged, and the
+// rows `write_cells_csv` writes.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::PathBuf;
+
+use flate2::read::GzDecoder;
+use powercela::output::{read_result, write_cells_csv, write_result, Compression, OutputFormat};
+use powercela::{GameOfLife, Point, SimulationResult};
+
+fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("powercela-{}-{}", std::process::id(), name))
+}
+
+fn beacon_result() -> SimulationResult {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named("beacon", 0, 0).unwrap();
+    let fingerprint = game.pattern_fingerprint();
+    let kind = game.simulate(100, None);
+    SimulationResult::from_game(game, kind, fingerprint)
+}
+
+#[test]
+fn gzipped_json_round_trips() {
+    let result = beacon_result();
+    let path = write_result(&temp_path("beacon"), &result, OutputFormat::Json, Compression::Gzip(flate2::Compression::best())).unwrap();
+    assert_eq!(path.file_name().unwrap().to_str().unwrap(), format!("powercela-{}-beacon.json.gz", std::process::id()));
+
+    let mut json = String::new();
+    GzDecoder::new(File::open(&path).unwrap()).read_to_string(&mut json).unwrap();
+    let expected = serde_json::to_value(&result).unwrap();
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap(), expected);
+    assert_eq!(serde_json::to_value(read_result(&path).unwrap()).unwrap(), expected);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn uncompressed_json_has_no_gz_suffix() {
+    let result = beacon_result();
+    let path = write_result(&temp_path("plain"), &result, OutputFormat::Json, Compression::None).unwrap();
+    assert_eq!(path.extension().unwrap(), "json");
+    assert_eq!(serde_json::to_value(read_result(&path).unwrap()).unwrap(), serde_json::to_value(&result).unwrap());
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn cells_csv_has_a_row_per_cell_in_generation_order() {
+    let snapshots = HashMap::from([
+        (100, vec![Point::new(-1, 2)]),
+        (0, vec![Point::new(0, 0), Point::new(1, 0)]),
+    ]);
+    let path = temp_path("cells.csv");
+    write_cells_csv(&path, &snapshots).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "generation,x,y\n0,0,0\n0,1,0\n100,-1,2\n");
+    fs::remove_file(path).unwrap();
+}
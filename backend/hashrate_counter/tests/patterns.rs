@@ -0,0 +1,52 @@
+// Pattern library metadata and search.
+
+use std::collections::HashSet;
+
+use powercela::{GameOfLife, PatternCategory, PatternLibrary, PatternQuery, Point};
+
+// The cells moved so their bounding box starts at the origin.
+fn shape(game: &GameOfLife) -> HashSet<Point> {
+    let mut game = game.clone();
+    game.normalize();
+    game.live_cells
+}
+
+#[test]
+fn periods_match_the_simulation() {
+    for pattern in PatternLibrary::all() {
+        let (Some(period), true) = (pattern.period, pattern.category != PatternCategory::Gun) else {
+            continue;
+        };
+        let mut game = GameOfLife::new();
+        game.load_named(pattern.name, 0, 0).unwrap();
+        let start = game.live_cells.clone();
+        let start_shape = shape(&game);
+        let moves = pattern.category == PatternCategory::Spaceship;
+        for generation in 1..=period {
+            game.steps().next();
+            let repeated = if moves { shape(&game) == start_shape && game.live_cells != start } else { game.live_cells == start };
+            assert_eq!(repeated, generation == period, "{} at generation {}", pattern.name, generation);
+        }
+    }
+}
+
+#[test]
+fn searches_by_every_criterion() {
+    let names = |query: PatternQuery| PatternLibrary::search(query).iter().map(|pattern| pattern.name).collect::<Vec<_>>();
+    assert_eq!(names(PatternQuery::new().category(PatternCategory::Oscillator).period(3)), ["pulsar"]);
+    assert_eq!(names(PatternQuery::new().category(PatternCategory::StillLife)), ["block", "beehive"]);
+    assert_eq!(names(PatternQuery::new().period(4).min_cells(10)), ["mwss", "hwss"]);
+    assert_eq!(names(PatternQuery::new().max_cells(4)), ["blinker", "block"]);
+    assert_eq!(names(PatternQuery::new().name_contains("WSS").max_cells(11)), ["lwss", "mwss"]);
+    assert_eq!(names(PatternQuery::new()).len(), PatternLibrary::all().len());
+    assert!(names(PatternQuery::new().category(PatternCategory::Gun).period(2)).is_empty());
+}
+
+#[test]
+fn categories_parse_and_print() {
+    for name in ["still-life", "oscillator", "spaceship", "gun", "other"] {
+        assert_eq!(name.parse::<PatternCategory>().unwrap().to_string(), name);
+    }
+    assert_eq!("Still_Life".parse::<PatternCategory>().unwrap(), PatternCategory::StillLife);
+    assert!("pattern".parse::<PatternCategory>().is_err());
+}
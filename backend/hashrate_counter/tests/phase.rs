@@ -0,0 +1,74 @@
+// Periods found by `detect_phase` and by the stability check in `simulate`,
+// and `current_stability_score`.
+
+use powercela::{current_stability_score, detect_phase, GameOfLife, SimulationResult, StabilizationKind};
+
+#[test]
+fn finds_the_smallest_period() {
+    let cycle = [4, 9, 9, 1, 7, 7, 7, 2, 3, 5, 5, 8, 6, 6];
+    let history: Vec<usize> = cycle.iter().copied().cycle().take(200).collect();
+    assert_eq!(detect_phase(&history, 64), Some(14));
+    assert_eq!(detect_phase(&history, 13), None);
+}
+
+#[test]
+fn ignores_constant_and_short_histories() {
+    assert_eq!(detect_phase(&[5; 200], 64), None);
+    let history: Vec<usize> = [1, 2].iter().copied().cycle().take(127).collect();
+    assert_eq!(detect_phase(&history, 64), None);
+}
+
+#[test]
+fn pentadecathlon_stops_with_period_15() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(&["1111111111"], 0, 0).unwrap();
+    match game.simulate(1000, None) {
+        StabilizationKind::Oscillator { period, .. } => assert_eq!(period, 15),
+        other => panic!("expected an oscillator, got {:?}", other),
+    }
+}
+
+// The first generation `StabilityTracker` stops at for populations repeating
+// `cycle`, and how.
+fn tracked_stop(cycle: &[usize]) -> (usize, StabilizationKind) {
+    let mut populations = cycle.iter().copied().cycle();
+    let mut tracker = powercela_core::StabilityTracker::new(50, populations.next().unwrap());
+    (1..1000)
+        .find_map(|generation| Some((generation, tracker.observe(generation, populations.next().unwrap())?)))
+        .unwrap()
+}
+
+#[test]
+fn short_periods_are_oscillators_and_long_ones_periodic() {
+    let short: Vec<usize> = (1..=15).collect();
+    assert_eq!(tracked_stop(&short), (63, StabilizationKind::Oscillator { generation: 0, period: 15 }));
+
+    let long: Vec<usize> = (1..=40).collect();
+    assert_eq!(tracked_stop(&long), (127, StabilizationKind::Periodic { period: 40, generation: 0 }));
+    assert!(StabilizationKind::Periodic { period: 40, generation: 0 }.is_settled());
+    assert_eq!(StabilizationKind::Periodic { period: 40, generation: 7 }.generation(), Some(7));
+}
+
+#[test]
+fn stability_score_is_the_constant_share_of_the_window() {
+    let mut history = vec![3, 8, 5];
+    history.extend([7; 30]);
+    assert_eq!(current_stability_score(&history, 50), 0.6);
+    history.extend([7; 40]);
+    assert_eq!(current_stability_score(&history, 50), 1.0);
+    assert_eq!(current_stability_score(&[], 50), 0.0);
+}
+
+#[test]
+fn final_stability_score() {
+    let run = |pattern: &[&str], iterations| {
+        let mut game = GameOfLife::new();
+        game.set_quiet(true);
+        game.initialize_pattern(pattern, 0, 0).unwrap();
+        let kind = game.simulate(iterations, None);
+        SimulationResult::from_game(game, kind, 0).final_stability_score
+    };
+    assert_eq!(run(&["11", "11"], 200), 1.0);
+    assert!(run(&["011", "110", "010"], 100) < 1.0);
+}
@@ -0,0 +1,59 @@
+// `quadrant_counts` and the quadrant series `track_quadrants` records.
+
+use powercela::quadrants::quadrant_counts;
+use powercela::GameOfLife;
+
+const PULSAR: [&str; 13] = [
+    "0011100011100",
+    "0000000000000",
+    "1000010100001",
+    "1000010100001",
+    "1000010100001",
+    "0011100011100",
+    "0000000000000",
+    "0011100011100",
+    "1000010100001",
+    "1000010100001",
+    "1000010100001",
+    "0000000000000",
+    "0011100011100",
+];
+
+fn tracked(pattern: &[&str], x: i64, y: i64) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.set_track_quadrants(true);
+    game.set_stability_window(1000);
+    game.initialize_pattern(pattern, x, y).unwrap();
+    game
+}
+
+#[test]
+fn a_symmetric_pattern_fills_the_quadrants_equally() {
+    // Centered on the origin, the pulsar's empty middle row and column lie on
+    // the axes, so every phase splits evenly.
+    let mut game = tracked(&PULSAR, -6, -6);
+    assert_eq!(quadrant_counts(&game.live_cells), [12; 4]);
+    game.simulate(6, None);
+    for (generation, counts) in &game.quadrant_series {
+        assert!(counts.iter().all(|&count| count == counts[0]), "generation {}: {:?}", generation, counts);
+    }
+    assert_eq!(game.quadrant_series[&1], [14; 4]);
+}
+
+#[test]
+fn a_glider_heading_for_q1_ends_up_there() {
+    // This glider moves one cell towards increasing x and y every four
+    // generations, from Q3 across the origin into Q1.
+    let mut game = tracked(&["010", "001", "111"], -12, -12);
+    assert_eq!(quadrant_counts(&game.live_cells), [0, 0, 5, 0]);
+    game.simulate(100, None);
+
+    let q1: Vec<usize> = game.quadrant_series.values().map(|counts| counts[0]).collect();
+    assert_eq!(q1[0], 0);
+    assert_eq!(*q1.last().unwrap(), 5);
+    assert_eq!(game.quadrant_series[&100], [5, 0, 0, 0]);
+    // Q1 only fills up once the glider reaches it.
+    let first = q1.iter().position(|&count| count > 0).unwrap();
+    assert!(first > 30, "{}", first);
+}
@@ -0,0 +1,29 @@
+// Round trips through `rasterize` and `load_raster`.
+
+use powercela::{GameOfLife, Point};
+
+#[test]
+fn round_trip_keeps_the_cells() {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(&["011", "110", "010"], -7, 12).unwrap();
+    game.set_cell(3, 20, true);
+
+    let (grid, bbox) = game.rasterize().unwrap();
+    assert_eq!((grid.len(), grid[0].len()), (bbox.height() as usize, bbox.width() as usize));
+    assert_eq!(grid.iter().flatten().filter(|&&alive| alive).count(), game.live_cells.len());
+
+    let rows: Vec<&[bool]> = grid.iter().map(Vec::as_slice).collect();
+    let mut copy = GameOfLife::new();
+    copy.load_raster(&rows, Point::new(bbox.min_x, bbox.min_y));
+    assert_eq!(copy.live_cells, game.live_cells);
+}
+
+#[test]
+fn grid_indices_map_to_the_bounding_box() {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(&["111"], 5, -2).unwrap();
+    let (grid, bbox) = game.rasterize().unwrap();
+    assert_eq!(grid, vec![vec![true, true, true]]);
+    assert_eq!((bbox.min_x, bbox.min_y), (5, -2));
+    assert!(GameOfLife::new().rasterize().is_none());
+}
@@ -0,0 +1,39 @@
+// Image sizes of `render_png` and `render_svg` at different cell sizes.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use powercela::render::{render_png, render_svg};
+use powercela::GameOfLife;
+
+fn blinker() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.initialize_pattern(&["111"], 0, 0).unwrap();
+    game
+}
+
+fn temp_file(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("powercela-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn png_cells_are_cell_size_blocks() {
+    let path = temp_file("blinker.png");
+    render_png(&blinker().live_cells, 4, &path).unwrap();
+    let image = image::open(&path).unwrap().to_rgba8();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(image.dimensions(), (12, 4));
+    assert!(image.pixels().all(|pixel| pixel.0 == [0, 0, 0, 255]));
+}
+
+#[test]
+fn svg_rects_are_cell_size_squares() {
+    let path = temp_file("blinker.svg");
+    render_svg(&blinker().live_cells, 8, &path).unwrap();
+    let svg = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(svg.contains(r#"width="24" height="8" viewBox="0 0 24 8""#), "{}", svg);
+    assert!(svg.contains(r#"<rect x="16" y="0" width="8" height="8"/>"#), "{}", svg);
+    assert_eq!(svg.matches(r#"width="8" height="8""#).count(), 3);
+}
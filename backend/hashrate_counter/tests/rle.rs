@@ -0,0 +1,70 @@
+// Parsing RLE files, including Golly's extended headers.
+
+use powercela::formats::rle::{parse_rle, RleMetadata};
+use powercela::formats::ParseError;
+use powercela::{GameOfLife, Point, StabilizationKind};
+
+// As Golly saves a glider after running it for a while.
+const GOLLY_GLIDER: &str = "#N Glider
+#O Richard K. Guy
+#C The smallest spaceship.
+#CXRLE Pos=-10,-5 Gen=1234
+x = 3, y = 3, rule = B3/S23
+bo$2bo$3o!
+";
+
+#[test]
+fn reads_golly_metadata() {
+    let (metadata, game) = GameOfLife::load_rle_extended(GOLLY_GLIDER).unwrap();
+    assert_eq!(metadata, RleMetadata { pos_x: -10, pos_y: -5, generation: 1234, name: Some("Glider".to_string()) });
+
+    let expected = [(-9, -5), (-8, -4), (-10, -3), (-9, -3), (-8, -3)];
+    assert_eq!(game.live_cells, expected.iter().map(|&(x, y)| Point::new(x, y)).collect());
+}
+
+#[test]
+fn metadata_defaults_to_the_centered_corner() {
+    let (metadata, game) = GameOfLife::load_rle_extended("x = 3, y = 1\n3o!\n").unwrap();
+    assert_eq!(metadata, RleMetadata { pos_x: -1, pos_y: 0, generation: 0, name: None });
+    assert_eq!(game.live_cells.len(), 3);
+}
+
+#[test]
+fn rejects_a_malformed_generation() {
+    let source = "#CXRLE Pos=0,0 Gen=soon\nx = 1, y = 1\no!\n";
+    let error = GameOfLife::load_rle_extended(source).err();
+    assert_eq!(error, Some(ParseError::InvalidHeader("Gen=soon".to_string())));
+}
+
+#[test]
+fn parses_a_glider() {
+    let pattern = parse_rle("#N Glider\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n").unwrap();
+    assert_eq!((pattern.width, pattern.height, pattern.rule.as_deref()), (3, 3, Some("B3/S23")));
+    let expected = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+    assert_eq!(pattern.cells, expected.iter().map(|&(x, y)| Point::new(x, y)).collect::<Vec<_>>());
+}
+
+#[test]
+fn a_loaded_blinker_oscillates() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    // The body may be wrapped across lines.
+    game.load_rle("x = 3, y = 1\n2o\no!\n").unwrap();
+    assert_eq!(game.live_cells, [(-1, 0), (0, 0), (1, 0)].iter().map(|&(x, y)| Point::new(x, y)).collect());
+    assert!(matches!(game.simulate(100, None), StabilizationKind::FixedPoint(_) | StabilizationKind::Oscillator { .. }));
+    assert!(game.generation_data.values().all(|&population| population == 3));
+}
+
+#[test]
+fn rejects_malformed_files() {
+    let cases = [
+        ("bo$2bo$3o!\n", ParseError::InvalidHeader("bo$2bo$3o!".to_string())),
+        ("x = 3, y = 3\nbo$2bo$3o\n", ParseError::MissingTerminator),
+        ("x = 3, y = 3\nbo$2bx$3o!\n", ParseError::UnknownCharacter('x')),
+        ("x = 3, y = 3\nbo$0bo$3o!\n", ParseError::MalformedRunCount("0".to_string())),
+        ("x = 3, y = 3\nbo$2bo$4o!\n", ParseError::OutOfBounds(3, 3)),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(parse_rle(source).err(), Some(expected), "{:?}", source);
+    }
+}
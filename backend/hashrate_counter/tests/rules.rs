@@ -0,0 +1,38 @@
+// The named life-like rules behave as documented.
+
+use powercela::{GameOfLife, RandomSpec, Rule};
+
+const REPLICATOR: [&str; 5] = ["00111", "01001", "10001", "10010", "11100"];
+
+fn populations(rule: Rule, pattern: &[&str], iterations: usize) -> Vec<usize> {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.set_rule(rule);
+    game.initialize_pattern_centered(pattern).unwrap();
+    game.simulate(iterations, None);
+    game.generation_data.into_values().collect()
+}
+
+#[test]
+fn highlife_and_conway_part_at_the_first_birth_on_six() {
+    let conway = populations(Rule::conway(), &REPLICATOR, 12);
+    let highlife = populations(Rule::highlife(), &REPLICATOR, 12);
+    assert_eq!(conway, [12, 20, 16, 28, 24, 32, 28, 28, 40, 32, 44, 32, 32]);
+    // By generation 12 the replicator has made two copies of itself.
+    assert_eq!(highlife, [12, 20, 17, 30, 20, 28, 34, 38, 26, 17, 16, 22, 24]);
+}
+
+#[test]
+fn a_day_and_night_soup_shrinks_without_dying_out() {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.set_rule(Rule::day_and_night());
+    game.set_stability_window(1000);
+    game.load_random(&RandomSpec { width: 64, height: 64, density: 0.5, seed: 0 }).unwrap();
+    let start = game.live_cells.len();
+    game.simulate(1000, None);
+
+    assert_eq!(game.generation_data.len(), 1001);
+    let late = game.generation_data.range(500..).map(|(_, &population)| population);
+    assert!(late.clone().all(|population| 0 < population && population < start / 2), "{:?}", late.collect::<Vec<_>>());
+}
@@ -0,0 +1,208 @@
+// The HTTP API (server feature).
+#![cfg(feature = "server")]
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use powercela::server::router;
+use powercela::SimulationResult;
+use tokio::runtime::Runtime;
+
+fn start() -> SocketAddr {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    listener.set_nonblocking(true).unwrap();
+    thread::spawn(move || {
+        Runtime::new().unwrap().block_on(async {
+            let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+            axum::serve(listener, router()).await.unwrap();
+        })
+    });
+    address
+}
+
+// Opens a WebSocket, without the handshake checks a real client makes.
+fn connect(address: SocketAddr, path: &str) -> TcpStream {
+    let (stream, head) = upgrade(address, path);
+    assert!(head.starts_with("HTTP/1.1 101"), "{}", head);
+    stream
+}
+
+// Sends the upgrade request and returns the response head.
+fn upgrade(address: SocketAddr, path: &str) -> (TcpStream, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n",
+        path
+    )
+    .unwrap();
+    let mut head = Vec::new();
+    while !head.ends_with(b"\r\n\r\n") {
+        let mut byte = [0];
+        stream.read_exact(&mut byte).unwrap();
+        head.push(byte[0]);
+    }
+    (stream, String::from_utf8_lossy(&head).into_owned())
+}
+
+// The next text message, or `None` once the server closes the socket.
+fn receive(stream: &mut TcpStream) -> Option<serde_json::Value> {
+    let mut header = [0; 2];
+    stream.read_exact(&mut header).unwrap();
+    let length = match header[1] & 0x7f {
+        126 => {
+            let mut length = [0; 2];
+            stream.read_exact(&mut length).unwrap();
+            u16::from_be_bytes(length) as usize
+        }
+        127 => {
+            let mut length = [0; 8];
+            stream.read_exact(&mut length).unwrap();
+            u64::from_be_bytes(length) as usize
+        }
+        length => length as usize,
+    };
+    let mut payload = vec![0; length];
+    stream.read_exact(&mut payload).unwrap();
+    match header[0] & 0x0f {
+        1 => Some(serde_json::from_slice(&payload).unwrap()),
+        8 => None,
+        opcode => panic!("unexpected opcode {}", opcode),
+    }
+}
+
+// Client frames have to be masked.
+fn send(stream: &mut TcpStream, text: &str) {
+    let mask = [1, 2, 3, 4];
+    let mut frame = vec![0x81, 0x80 | text.len() as u8];
+    frame.extend(mask);
+    frame.extend(text.bytes().enumerate().map(|(i, byte)| byte ^ mask[i % 4]));
+    stream.write_all(&frame).unwrap();
+}
+
+// Returns the status code and the body.
+fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response.split_whitespace().nth(1).unwrap().parse().unwrap();
+    let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+    (status, body)
+}
+
+#[test]
+fn health_and_patterns() {
+    let address = start();
+    assert_eq!(request(address, "GET", "/health", ""), (200, r#"{"status":"ok"}"#.to_string()));
+
+    let (status, body) = request(address, "GET", "/patterns", "");
+    assert_eq!(status, 200);
+    let patterns: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+    assert!(patterns.iter().any(|pattern| pattern["name"] == "glider" && pattern["width"] == 3));
+}
+
+#[test]
+fn simulates_named_and_flat_patterns() {
+    let address = start();
+    let (status, body) = request(address, "POST", "/simulate", r#"{"pattern": "blinker", "iterations": 10}"#);
+    assert_eq!(status, 200);
+    let result: SimulationResult = serde_json::from_str(&body).unwrap();
+    assert_eq!(result.generations.len(), 11);
+    assert!(result.generations.values().all(|&population| population == 3));
+
+    let (status, body) = request(address, "POST", "/simulate", r#"{"pattern": "010001111", "splitAmount": 3, "iterations": 4}"#);
+    assert_eq!(status, 200);
+    let result: SimulationResult = serde_json::from_str(&body).unwrap();
+    assert_eq!(result.generations.values().copied().collect::<Vec<_>>(), vec![5; 5]);
+}
+
+#[test]
+fn bad_requests_get_a_400() {
+    let address = start();
+    let (status, body) = request(address, "POST", "/simulate", r#"{"pattern": "nope", "iterations": 5}"#);
+    assert_eq!(status, 400);
+    assert!(body.contains("unknown pattern"));
+
+    assert_eq!(request(address, "POST", "/simulate", r#"{"pattern": "glider"}"#).0, 400);
+    assert_eq!(request(address, "POST", "/simulate", r#"{"pattern": "glider", "iterations": 100000000}"#).0, 400);
+}
+
+#[test]
+fn websocket_streams_every_generation() {
+    let address = start();
+    let mut socket = connect(address, "/ws/simulate?pattern=blinker&iterations=5");
+    let mut messages = Vec::new();
+    while let Some(message) = receive(&mut socket) {
+        messages.push(message);
+    }
+    let generations: Vec<serde_json::Value> = (0..=5).map(|generation| serde_json::json!({ "gen": generation, "pop": 3 })).collect();
+    assert_eq!(messages[..6], generations[..]);
+    assert_eq!(messages[6], serde_json::json!({ "done": true, "stabilizationKind": "none" }));
+    assert_eq!(messages.len(), 7);
+}
+
+#[test]
+fn the_client_that_starts_a_run_gets_every_generation() {
+    // Many more generations than a watcher may fall behind by, read only
+    // after the simulation has had time to run ahead.
+    let address = start();
+    let mut socket = connect(address, "/ws/simulate?pattern=gosper-glider-gun&iterations=3000");
+    thread::sleep(Duration::from_millis(500));
+    let mut generations = Vec::new();
+    let mut last = None;
+    while let Some(message) = receive(&mut socket) {
+        match message["gen"].as_u64() {
+            Some(generation) => generations.push(generation),
+            None => last = Some(message),
+        }
+    }
+    assert_eq!(generations, (0..=3000).collect::<Vec<_>>());
+    assert_eq!(last.unwrap()["done"], true);
+}
+
+#[test]
+fn watchers_join_a_running_simulation() {
+    let address = start();
+    let path = "/ws/simulate?pattern=gosper-glider-gun&iterations=100000";
+    let mut owner = connect(address, path);
+    assert_eq!(receive(&mut owner).unwrap()["gen"], 0);
+
+    let mut watcher = connect(address, path);
+    assert!(receive(&mut watcher).unwrap()["gen"].as_u64().unwrap() > 0);
+    // A watcher's stop only closes its own socket.
+    send(&mut watcher, r#"{"stop": true}"#);
+    while receive(&mut watcher).is_some() {}
+    let generation = receive(&mut owner).unwrap()["gen"].as_u64().unwrap();
+    assert!(receive(&mut owner).unwrap()["gen"].as_u64().unwrap() > generation);
+
+    send(&mut owner, r#"{"stop": true}"#);
+    let mut last = None;
+    while let Some(message) = receive(&mut owner) {
+        last = Some(message);
+    }
+    assert!(last.is_some_and(|message| message["gen"].as_u64().unwrap() < 100_000));
+}
+
+#[test]
+fn websocket_rejects_bad_queries() {
+    let address = start();
+    let (mut stream, head) = upgrade(address, "/ws/simulate?pattern=nope&iterations=5");
+    assert!(head.starts_with("HTTP/1.1 400"), "{}", head);
+    let length = head.lines().find_map(|line| line.strip_prefix("content-length: ")).unwrap().parse().unwrap();
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).unwrap();
+    assert!(String::from_utf8_lossy(&body).contains("unknown pattern"));
+    assert!(upgrade(address, "/ws/simulate?pattern=glider").1.starts_with("HTTP/1.1 400"));
+}
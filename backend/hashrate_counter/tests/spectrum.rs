@@ -0,0 +1,29 @@
+// Dominant periods found by `population_spectrum`.
+
+use std::f64::consts::PI;
+
+use powercela::spectrum::{dominant_period, population_spectrum};
+
+fn sine(len: usize, period: f64) -> Vec<usize> {
+    (0..len).map(|i| (100.0 + 40.0 * (2.0 * PI * i as f64 / period).sin()).round() as usize).collect()
+}
+
+#[test]
+fn sine_wave_on_a_power_of_two() {
+    let history = sine(256, 16.0);
+    assert_eq!(population_spectrum(&history).len(), 129);
+    assert_eq!(dominant_period(&history), Some(16.0));
+}
+
+#[test]
+fn sine_wave_with_padding() {
+    // 300 generations pad to 512, so the period falls between bins.
+    let period = dominant_period(&sine(300, 20.0)).unwrap();
+    assert!((period - 20.0).abs() < 1.0, "{}", period);
+}
+
+#[test]
+fn constant_series_has_no_period() {
+    assert_eq!(dominant_period(&[12; 64]), None);
+    assert_eq!(dominant_period(&[]), None);
+}
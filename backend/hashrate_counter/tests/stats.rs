@@ -0,0 +1,51 @@
+// `PopulationStats` of known population series.
+
+use std::collections::BTreeMap;
+
+use powercela::{GameOfLife, PopulationStats};
+
+fn populations(name: &str, iterations: usize) -> BTreeMap<usize, usize> {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.set_stability_window(1000);
+    game.load_named(name, 0, 0).unwrap();
+    game.simulate(iterations, None);
+    game.generation_data
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{} != {}", actual, expected);
+}
+
+#[test]
+fn a_still_life_has_no_variance() {
+    let stats = PopulationStats::from_generations(&populations("block", 20));
+    assert_eq!(stats, PopulationStats { min: 4, max: 4, mean: 4.0, variance: 0.0 });
+}
+
+#[test]
+fn the_pulsar_cycles_through_48_56_and_72_cells() {
+    let data = populations("pulsar", 2);
+    assert_eq!(data.values().copied().collect::<Vec<_>>(), [48, 56, 72]);
+    let stats = PopulationStats::from_generations(&data);
+    assert_eq!((stats.min, stats.max), (48, 72));
+    assert_close(stats.mean, 176.0 / 3.0);
+    assert_close(stats.variance, 896.0 / 9.0);
+
+    // Whole periods give the same mean and variance.
+    let stats = PopulationStats::from_generations(&populations("pulsar", 29));
+    assert_close(stats.mean, 176.0 / 3.0);
+    assert_close(stats.variance, 896.0 / 9.0);
+}
+
+#[test]
+fn variance_is_the_population_variance() {
+    let data: BTreeMap<usize, usize> = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().enumerate().collect();
+    let stats = PopulationStats::from_generations(&data);
+    assert_eq!(stats, PopulationStats { min: 2, max: 9, mean: 5.0, variance: 4.0 });
+}
+
+#[test]
+fn no_generations_give_zeros() {
+    assert_eq!(PopulationStats::from_generations(&BTreeMap::new()), PopulationStats::default());
+}
@@ -0,0 +1,35 @@
+// `simulate_until` stops at the first generation its predicate matches.
+
+use powercela::{GameOfLife, Rule, StopReason};
+
+fn named(name: &str) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named(name, 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn stops_at_the_generation_asked_for() {
+    let mut game = named("gosper-glider-gun");
+    assert_eq!(game.simulate_until(1000, |generation, _| generation == 42), StopReason::Predicate(42));
+    assert_eq!(game.generation_data.keys().next_back(), Some(&42));
+}
+
+#[test]
+fn stops_once_the_population_passes_a_bound() {
+    // Life without death never loses a cell, so the R-pentomino keeps growing.
+    let mut game = named("r-pentomino");
+    game.set_rule("B3/S012345678".parse::<Rule>().unwrap());
+    let reason = game.simulate_until(10_000, |_, cells| cells.len() > 1000);
+    let StopReason::Predicate(generation) = reason else { panic!("{:?}", reason) };
+    assert!(game.generation_data[&generation] > 1000);
+    assert!(game.generation_data.range(..generation).all(|(_, &population)| population <= 1000));
+    assert_eq!(game.live_cells.len(), game.generation_data[&generation]);
+}
+
+#[test]
+fn runs_to_the_end_when_the_predicate_never_matches() {
+    let mut game = named("gosper-glider-gun");
+    assert_eq!(game.simulate_until(60, |_, _| false), StopReason::Completed);
+}
@@ -0,0 +1,39 @@
+// `longestStabilityStreak` over whole runs.
+
+use powercela::{GameOfLife, SimulationResult};
+
+fn result(pattern: &[&str], iterations: usize) -> SimulationResult {
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.initialize_pattern(pattern, 0, 0).unwrap();
+    let kind = game.simulate(iterations, None);
+    SimulationResult::from_game(game, kind, 0)
+}
+
+#[test]
+fn glider_is_one_streak() {
+    let result = result(&["010", "001", "111"], 40);
+    assert_eq!(result.longest_stability_streak, result.generations.len());
+    assert_eq!(result.streak_start_generation, 0);
+}
+
+#[test]
+fn growing_pattern_has_a_short_streak() {
+    // The glider gun's population keeps changing while gliders are emitted.
+    let mut game = GameOfLife::new();
+    game.set_quiet(true);
+    game.load_named("gosper-glider-gun", 0, 0).unwrap();
+    let kind = game.simulate(300, None);
+    let result = SimulationResult::from_game(game, kind, 0);
+    assert_eq!(result.generations.len(), 301);
+    assert!(result.longest_stability_streak < 10, "{}", result.longest_stability_streak);
+}
+
+#[test]
+fn r_pentomino_streak_has_one_population() {
+    let result = result(&["011", "110", "010"], 100);
+    let start = result.streak_start_generation;
+    let population = result.generations[&start];
+    let streak = start..start + result.longest_stability_streak;
+    assert!(streak.clone().all(|generation| result.generations[&generation] == population));
+}
@@ -0,0 +1,22 @@
+// `rule_sweep` matches simulating each rule on its own.
+
+use powercela::{rule_sweep, GameOfLife, Rule, SimulationResult};
+
+#[test]
+fn sweep_matches_single_runs() {
+    let mut pattern = GameOfLife::new();
+    pattern.set_quiet(true);
+    pattern.initialize_pattern(&["011", "110", "010"], 0, 0).unwrap();
+    let rules = vec![Rule::conway(), Rule::highlife(), Rule::day_and_night(), "B2/S".parse().unwrap()];
+
+    let results = rule_sweep(pattern.clone(), rules.clone(), 150);
+    assert_eq!(results.iter().map(|(rule, _)| rule.clone()).collect::<Vec<_>>(), rules);
+    for (rule, result) in results {
+        let mut game = pattern.clone();
+        game.set_rule(rule.clone());
+        let kind = game.simulate(150, None);
+        let expected = SimulationResult::from_game(game, kind, 0);
+        assert_eq!(result.generations, expected.generations, "{}", rule);
+        assert_eq!(result.stabilization_kind, expected.stabilization_kind, "{}", rule);
+    }
+}
@@ -0,0 +1,55 @@
+// `tournament` picks its winner by the chosen criterion.
+
+use powercela::{tournament, tournament_by, GameOfLife, PatternId, StabilizationKind, WinCriterion};
+
+fn named(name: &str) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.load_named(name, 0, 0).unwrap();
+    game
+}
+
+#[test]
+fn a_settled_pattern_beats_one_still_growing() {
+    let result = tournament(named("gosper-glider-gun"), named("block"), 200);
+    assert_eq!(result.winner, Some(PatternId::B));
+    assert_eq!(result.winner_by, WinCriterion::StabilizesFirst);
+    assert_eq!(result.a_result.stabilization_kind, StabilizationKind::None);
+    assert!(result.b_result.stabilization_kind.is_settled());
+}
+
+#[test]
+fn neither_wins_when_neither_settles() {
+    let result = tournament(named("gosper-glider-gun"), named("gosper-glider-gun"), 100);
+    assert_eq!(result.winner, None);
+}
+
+#[test]
+fn population_criteria_compare_the_last_generation() {
+    let larger = tournament_by(named("blinker"), named("block"), 100, WinCriterion::LargerFinalPopulation);
+    assert_eq!(larger.winner, Some(PatternId::B));
+    assert_eq!((larger.final_population(PatternId::A), larger.final_population(PatternId::B)), (3, 4));
+
+    let lower = tournament_by(named("blinker"), named("block"), 100, WinCriterion::LowerFinalPopulation);
+    assert_eq!(lower.winner, Some(PatternId::A));
+    assert_eq!(lower.winner_by, WinCriterion::LowerFinalPopulation);
+}
+
+#[test]
+fn results_match_single_runs() {
+    let result = tournament(named("r-pentomino"), named("glider"), 300);
+    for (id, name) in [(PatternId::A, "r-pentomino"), (PatternId::B, "glider")] {
+        let mut game = named(name);
+        game.set_quiet(true);
+        let kind = game.simulate(300, None);
+        assert_eq!(result.result(id).stabilization_kind, kind, "{}", name);
+        assert_eq!(result.result(id).generations, game.generation_data, "{}", name);
+    }
+}
+
+#[test]
+fn criteria_parse_from_their_names() {
+    for criterion in [WinCriterion::StabilizesFirst, WinCriterion::LargerFinalPopulation, WinCriterion::LowerFinalPopulation] {
+        assert_eq!(criterion.to_string().parse::<WinCriterion>().unwrap(), criterion);
+    }
+    assert!("first".parse::<WinCriterion>().is_err());
+}
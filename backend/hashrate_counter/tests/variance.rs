@@ -0,0 +1,30 @@
+// `rolling_variance` and the low-variance stop.
+
+use powercela::stats::rolling_variance;
+use powercela::{BuildError, GameOfLife, StabilizationKind};
+
+#[test]
+fn variance_of_the_last_values() {
+    assert_eq!(rolling_variance(&[100, 1, 3, 1, 3], 4), 1.0);
+    assert_eq!(rolling_variance(&[2, 4], 10), 1.0);
+    assert_eq!(rolling_variance(&[], 10), 0.0);
+}
+
+fn pulsar(threshold: f64, window: usize) -> StabilizationKind {
+    let mut game = GameOfLife::builder().stop_variance(threshold, window).build().unwrap();
+    game.set_quiet(true);
+    game.load_named("pulsar", 0, 0).unwrap();
+    game.simulate(1000, None)
+}
+
+#[test]
+fn pulsar_stops_once_its_variance_is_below_the_threshold() {
+    // The pulsar cycles through 48, 56 and 72 cells, a variance of about 99.6.
+    assert_eq!(pulsar(100.0, 30), StabilizationKind::LowVariance { generation: 29, threshold: 100.0, window: 30 });
+    assert!(matches!(pulsar(50.0, 30), StabilizationKind::Oscillator { period: 3, .. }));
+}
+
+#[test]
+fn window_needs_two_populations() {
+    assert_eq!(GameOfLife::builder().stop_variance(1.0, 1).build().err(), Some(BuildError::InvalidVarianceWindow(1)));
+}